@@ -215,6 +215,11 @@ impl Eloquent {
     pub fn subquery() -> SubqueryBuilder {
         SubqueryBuilder::new()
     }
+
+    /// Create a new transaction block.
+    pub fn transaction() -> TransactionBlock {
+        TransactionBlock::new()
+    }
 }
 
 #[cfg(test)]
@@ -252,4 +257,26 @@ mod tests {
             "SELECT (SELECT AVG(duration_in_min) AS avg_duration_in_min FROM flights) AS avg_duration FROM flights"
         );
     }
+
+    #[test]
+    fn test_transaction() {
+        let transaction = Eloquent::transaction()
+            .query(
+                Eloquent::query()
+                    .table("flights")
+                    .insert("flight_number", "KL123")
+                    .insert("origin_airport", "AMS"),
+            )
+            .query(
+                Eloquent::query()
+                    .table("flights")
+                    .insert("flight_number", "LH456")
+                    .insert("origin_airport", "FRA"),
+            );
+
+        assert_eq!(
+            transaction.sql().unwrap(),
+            "BEGIN; INSERT INTO flights (flight_number, origin_airport) VALUES ('KL123', 'AMS'); INSERT INTO flights (flight_number, origin_airport) VALUES ('LH456', 'FRA'); COMMIT;"
+        );
+    }
 }
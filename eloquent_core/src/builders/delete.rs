@@ -1,7 +1,7 @@
 use crate::{
     compilers::{conditions, delete, havings, joins},
     error::EloquentError,
-    SqlBuilder,
+    Condition, SqlBuilder,
 };
 
 pub struct DeleteBuilder;
@@ -10,15 +10,30 @@ impl SqlBuilder for DeleteBuilder {
     fn build<'a>(
         builder: &'a crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<(dyn crate::ToSql + 'static)>>,
+        params: &mut Vec<&'a dyn crate::ToSql>,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
+        let table = builder.identifier_format().table(table);
 
-        delete::format(table, sql);
+        delete::format(&table, sql);
 
-        joins::format(&builder.joins, sql);
-        conditions::format(&builder.conditions, &builder.closures, &None, sql, params)?;
-        havings::format(&builder.havings, sql)?;
+        let start = sql.len();
+        joins::format(&builder.joins, builder.identifier_format(), sql, params)?;
+        builder.annotate_clause("joins", start, sql);
+
+        let start = sql.len();
+        conditions::format(
+            &builder.conditions,
+            &builder.closures,
+            &None,
+            &builder.cursor.iter().collect::<Vec<&Condition>>(),
+            builder.dialect,
+            sql,
+            params,
+        )?;
+        builder.annotate_clause("filters", start, sql);
+
+        havings::format(&builder.havings, builder.dialect, sql, params)?;
 
         Ok(sql.to_string())
     }
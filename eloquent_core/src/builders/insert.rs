@@ -1,4 +1,4 @@
-use crate::{compilers::inserts, error::EloquentError, SqlBuilder};
+use crate::{compilers::inserts, error::EloquentError, Dialect, SqlBuilder};
 
 pub struct InsertBuilder;
 
@@ -6,11 +6,50 @@ impl SqlBuilder for InsertBuilder {
     fn build<'a>(
         builder: &'a crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<(dyn crate::ToSql + 'static)>>,
+        params: &mut Vec<&'a dyn crate::ToSql>,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        inserts::format(table, &builder.inserts, sql, params);
+        if builder.conflict_target.is_some()
+            && matches!(builder.dialect, Dialect::MySql | Dialect::Mssql)
+        {
+            return Err(EloquentError::UnsupportedByDialect(
+                "ON CONFLICT".to_string(),
+            ));
+        }
+
+        if builder.inserted_flag.is_some() && builder.dialect != Dialect::Postgres {
+            return Err(EloquentError::UnsupportedByDialect("xmax".to_string()));
+        }
+
+        inserts::format(
+            table,
+            &builder.inserts,
+            &builder.insert_rows,
+            builder.identifier_format(),
+            sql,
+            params,
+        )?;
+        inserts::format_on_duplicate_key_update(
+            &builder.on_duplicate_key_updates,
+            &builder.conflict_target,
+            builder.identifier_format(),
+            sql,
+            params,
+        )?;
+        inserts::format_upsert(
+            &builder.upsert,
+            builder.dialect,
+            builder.identifier_format(),
+            sql,
+        )?;
+        inserts::format_returning(
+            &builder.returning,
+            &builder.inserted_flag,
+            builder.multiple_statements,
+            builder.identifier_format(),
+            sql,
+        )?;
 
         Ok(sql.to_string())
     }
@@ -0,0 +1,23 @@
+use crate::{compilers::merge, error::EloquentError, Dialect, SqlBuilder};
+
+pub struct MergeBuilder;
+
+impl SqlBuilder for MergeBuilder {
+    fn build<'a>(
+        builder: &'a crate::QueryBuilder,
+        sql: &mut String,
+        _params: &mut Vec<&'a dyn crate::ToSql>,
+    ) -> Result<String, EloquentError> {
+        let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
+
+        if matches!(builder.dialect, Dialect::MySql | Dialect::Sqlite) {
+            return Err(EloquentError::UnsupportedByDialect("MERGE".to_string()));
+        }
+
+        if let Some(merge) = &builder.merge {
+            merge::format(table, merge, builder.identifier_format(), sql)?;
+        }
+
+        Ok(sql.to_string())
+    }
+}
@@ -1,4 +1,5 @@
 pub mod delete;
 pub mod insert;
+pub mod merge;
 pub mod select;
 pub mod update;
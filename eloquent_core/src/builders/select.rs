@@ -1,7 +1,11 @@
 use crate::{
-    compilers::{conditions, group_by, havings, joins, limit, offset, order_by, selects},
+    compilers::{
+        conditions, from_function, from_values, group_by, havings, joins, limit, lock, offset,
+        order_by, selects, unions,
+    },
     error::EloquentError,
-    SqlBuilder,
+    split_table_alias, Condition, Dialect, OrderKind, QueryBuilder, SqlBuilder, ToSql, QUOTED_END,
+    QUOTED_START,
 };
 
 pub struct SelectBuilder;
@@ -10,25 +14,260 @@ impl SqlBuilder for SelectBuilder {
     fn build<'a>(
         builder: &'a crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<(dyn crate::ToSql + 'static)>>,
+        params: &mut Vec<&'a dyn crate::ToSql>,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        selects::format(table, &builder.selects, sql);
-        joins::format(&builder.joins, sql);
-        conditions::format(
-            &builder.conditions,
-            &builder.closures,
+        if builder.rewrites_distinct_on() {
+            return build_distinct_rewrite(builder, table, sql, params);
+        }
+
+        match &builder.shards {
+            Some(shards) if shards.count > 0 => {
+                for shard in 0..shards.count {
+                    if shard > 0 {
+                        sql.push_str(" UNION ALL ");
+                    }
+
+                    let shard_table = format!("{}{}", table, (shards.suffix)(shard));
+
+                    build_body(builder, &shard_table, sql, params)?;
+                }
+            }
+            _ => build_body(builder, table, sql, params)?,
+        }
+
+        unions::format(&builder.unions, builder.dialect, sql, params);
+
+        let start = sql.len();
+        let leading = unordered_distinct_on(builder);
+
+        if leading.is_empty() {
+            order_by::format(&builder.order_by, builder.dialect, sql, params)?;
+        } else {
+            // Postgres requires the DISTINCT ON columns to lead the ordering
+            let columns = order_by::format_columns(
+                &builder.order_by,
+                builder.dialect,
+                |column| column.to_string(),
+                params,
+            )?;
+
+            sql.push_str(&format!(" ORDER BY {}, {}", leading.join(", "), columns));
+        }
+        builder.annotate_clause("ordering", start, sql);
+
+        let start = sql.len();
+        limit::format(
+            &builder.limit,
+            &builder.offset,
             &builder.paginate,
+            !builder.order_by.is_empty(),
+            builder.dialect,
             sql,
             params,
-        )?;
-        group_by::format(&builder.group_by, sql);
-        havings::format(&builder.havings, sql)?;
-        order_by::format(&builder.order_by, sql);
-        limit::format(&builder.limit, &builder.paginate, sql);
-        offset::format(&builder.offset, sql);
+        );
+        offset::format(&builder.offset, builder.dialect, sql, params);
+        builder.annotate_clause("pagination", start, sql);
+
+        lock::format(&builder.lock, &builder.lock_wait, builder.dialect, sql)?;
 
         Ok(sql.to_string())
     }
 }
+
+fn build_body<'a>(
+    builder: &'a QueryBuilder,
+    table: &str,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<(), EloquentError> {
+    // the derived table values are bound after the select values
+    let mut from_params = Vec::new();
+
+    let table = match (
+        &builder.from_values,
+        &builder.from_function,
+        &builder.from_subquery,
+    ) {
+        (Some(values), _, _) => from_values::format(values, builder.dialect, &mut from_params),
+        (None, Some(function), _) => {
+            from_function::format(function, builder.dialect, &mut from_params)?
+        }
+        (None, None, Some(subquery)) => {
+            // the subquery is compiled in place of its placeholder
+            from_params.push(subquery.query.as_ref());
+
+            // subquery builders are compiled with their own parentheses
+            match subquery.query.is_subquery() {
+                true => builder.identifier_format().alias("?", table),
+                false => builder.identifier_format().alias("(?)", table),
+            }
+        }
+        (None, None, None) => builder.identifier_format().table(table),
+    };
+
+    // the window ordering is bound right after the select values
+    let mut row_number_params = Vec::new();
+
+    let row_number = builder
+        .rewrites_distinct_on()
+        .then(|| {
+            let order_by = match builder.order_by.is_empty() {
+                true => builder.distinct_on.join(", "),
+                false => order_by::format_columns(
+                    &builder.order_by,
+                    builder.dialect,
+                    |column| column.to_string(),
+                    &mut row_number_params,
+                )?,
+            };
+
+            Ok::<String, EloquentError>(format!(
+                "ROW_NUMBER() OVER (PARTITION BY {} ORDER BY {}) AS row_num",
+                builder.distinct_on.join(", "),
+                order_by
+            ))
+        })
+        .transpose()?;
+
+    let distinct_on = match builder.dialect {
+        Dialect::Postgres => builder.distinct_on.as_slice(),
+        _ => &[],
+    };
+
+    selects::format(
+        &table,
+        &builder.selects,
+        distinct_on,
+        row_number.as_deref(),
+        builder.identifier_format(),
+        sql,
+        params,
+    )?;
+    params.extend(row_number_params);
+    params.extend(from_params);
+
+    let start = sql.len();
+    joins::format(&builder.joins, builder.identifier_format(), sql, params)?;
+    builder.annotate_clause("joins", start, sql);
+
+    let start = sql.len();
+    conditions::format(
+        &builder.conditions,
+        &builder.closures,
+        &builder.paginate,
+        &builder.cursor.iter().collect::<Vec<&Condition>>(),
+        builder.dialect,
+        sql,
+        params,
+    )?;
+    builder.annotate_clause("filters", start, sql);
+
+    let start = sql.len();
+    group_by::format(
+        &builder.group_by_columns(),
+        builder.rollup,
+        builder.dialect,
+        sql,
+    )?;
+    builder.annotate_clause("grouping", start, sql);
+
+    let start = sql.len();
+    havings::format(&builder.havings, builder.dialect, sql, params)?;
+    builder.annotate_clause("group filters", start, sql);
+
+    Ok(())
+}
+
+/// The `DISTINCT ON` columns of a Postgres query missing from the start of its ordering.
+fn unordered_distinct_on(builder: &QueryBuilder) -> Vec<&str> {
+    if builder.dialect != Dialect::Postgres || builder.order_by.is_empty() {
+        return Vec::new();
+    }
+
+    let leading = builder
+        .order_by
+        .iter()
+        .take_while(|order| {
+            matches!(order.kind, OrderKind::Column) && builder.distinct_on.contains(&order.column)
+        })
+        .map(|order| order.column.as_str())
+        .collect::<Vec<&str>>();
+
+    builder
+        .distinct_on
+        .iter()
+        .map(String::as_str)
+        .filter(|column| !leading.contains(column))
+        .collect()
+}
+
+/// Emulate `DISTINCT ON` by keeping the first row of every partition.
+fn build_distinct_rewrite<'a>(
+    builder: &'a QueryBuilder,
+    table: &str,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    let unqualified = |column: &str| column.rsplit('.').next().unwrap_or(column).to_string();
+
+    // the outer query names every column it keeps, so the row number is never returned
+    if builder.selects.is_empty() {
+        return Err(EloquentError::InvalidDistinctOn("SELECT *".to_string()));
+    }
+
+    if builder.lock.is_some() {
+        return Err(EloquentError::InvalidDistinctOn("a row lock".to_string()));
+    }
+
+    if builder.shards.is_some() {
+        return Err(EloquentError::InvalidDistinctOn("shards".to_string()));
+    }
+
+    let columns = builder
+        .selects
+        .iter()
+        .map(|select| match &select.alias {
+            Some(alias) => Ok(alias.clone()),
+            None if select.function.is_none() && is_column_reference(&select.column) => {
+                Ok(unqualified(&select.column))
+            }
+            // an expression has no name the outer query could refer to
+            None => Err(EloquentError::InvalidDistinctOn(format!(
+                "the unaliased expression '{}'",
+                select.column
+            ))),
+        })
+        .collect::<Result<Vec<String>, EloquentError>>()?
+        .join(", ");
+
+    let alias = match split_table_alias(table) {
+        Some((name, alias)) => unqualified(alias.unwrap_or(name)),
+        None => "distinct_rows".to_string(),
+    };
+
+    sql.push_str(&format!("SELECT {} FROM (", columns));
+    build_body(builder, table, sql, params)?;
+    sql.push_str(&format!(") AS {} WHERE row_num = 1", alias));
+
+    order_by::format_renamed(&builder.order_by, builder.dialect, unqualified, sql, params)?;
+    limit::format(
+        &builder.limit,
+        &builder.offset,
+        &builder.paginate,
+        !builder.order_by.is_empty(),
+        builder.dialect,
+        sql,
+        params,
+    );
+    offset::format(&builder.offset, builder.dialect, sql, params);
+
+    Ok(sql.to_string())
+}
+
+fn is_column_reference(column: &str) -> bool {
+    column
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | QUOTED_START | QUOTED_END))
+}
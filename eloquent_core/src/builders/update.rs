@@ -1,7 +1,7 @@
 use crate::{
     compilers::{conditions, havings, joins, updates},
     error::EloquentError,
-    SqlBuilder,
+    Condition, Dialect, SqlBuilder,
 };
 
 pub struct UpdateBuilder;
@@ -10,14 +10,51 @@ impl SqlBuilder for UpdateBuilder {
     fn build<'a>(
         builder: &'a crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<(dyn crate::ToSql + 'static)>>,
+        params: &mut Vec<&'a dyn crate::ToSql>,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        updates::format(table, &builder.updates, sql, params);
-        joins::format(&builder.joins, sql);
-        conditions::format(&builder.conditions, &builder.closures, &None, sql, params)?;
-        havings::format(&builder.havings, sql)?;
+        // SQLite supports the form since 3.33
+        if builder.update_from.is_some()
+            && !matches!(builder.dialect, Dialect::Postgres | Dialect::Sqlite)
+        {
+            return Err(EloquentError::UnsupportedByDialect(
+                "UPDATE FROM".to_string(),
+            ));
+        }
+
+        updates::format(
+            table,
+            &builder.updates,
+            builder.update_from.as_ref().map(|from| from.table.as_str()),
+            builder.identifier_format(),
+            sql,
+            params,
+        );
+
+        let start = sql.len();
+        joins::format(&builder.joins, builder.identifier_format(), sql, params)?;
+        builder.annotate_clause("joins", start, sql);
+
+        let start = sql.len();
+        conditions::format(
+            &builder.conditions,
+            &builder.closures,
+            &None,
+            // the join condition of the source table applies to every row
+            &builder
+                .update_from
+                .iter()
+                .map(|from| &from.condition)
+                .chain(builder.cursor.iter())
+                .collect::<Vec<&Condition>>(),
+            builder.dialect,
+            sql,
+            params,
+        )?;
+        builder.annotate_clause("filters", start, sql);
+
+        havings::format(&builder.havings, builder.dialect, sql, params)?;
 
         Ok(sql.to_string())
     }
@@ -0,0 +1,61 @@
+use crate::{CaseBuilder, CaseWhen, ToSql};
+
+impl CaseBuilder {
+    /// Create a new `CASE` expression without any branches.
+    pub fn new() -> Self {
+        Self {
+            whens: Vec::new(),
+            else_value: None,
+        }
+    }
+
+    /// Add a branch returning `result` when the column equals `value`.
+    pub fn when(
+        mut self,
+        column: &str,
+        value: impl ToSql + 'static,
+        result: impl ToSql + 'static,
+    ) -> Self {
+        self.whens.push(CaseWhen {
+            column: column.to_string(),
+            value: Box::new(value),
+            result: Box::new(result),
+        });
+
+        self
+    }
+
+    /// Return `value` when none of the branches match, instead of `NULL`.
+    pub fn else_value(mut self, value: impl ToSql + 'static) -> Self {
+        self.else_value = Some(Box::new(value));
+
+        self
+    }
+
+    /// The expression with `?` placeholders, together with the values bound to them in order.
+    pub(crate) fn into_expression(self) -> (String, Vec<Box<dyn ToSql>>) {
+        let mut expression = "CASE".to_string();
+        let mut values = Vec::new();
+
+        for when in self.whens {
+            expression.push_str(&format!(" WHEN {} = ? THEN ?", when.column));
+            values.push(when.value);
+            values.push(when.result);
+        }
+
+        if let Some(else_value) = self.else_value {
+            expression.push_str(" ELSE ?");
+            values.push(else_value);
+        }
+
+        expression.push_str(" END");
+
+        (expression, values)
+    }
+}
+
+impl Default for CaseBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -24,6 +24,12 @@ impl PerformChecks for CannotApplyClauseOnDelete {
             return Err(EloquentError::CannotApplyClauseOnDelete("JOIN".to_string()));
         }
 
+        if !builder.returning.is_empty() {
+            return Err(EloquentError::CannotApplyClauseOnDelete(
+                "RETURNING".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,56 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct CannotApplyClauseOnMerge;
+
+impl PerformChecks for CannotApplyClauseOnMerge {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if builder.merge.is_none() {
+            return Ok(());
+        }
+
+        let clauses = [
+            ("SELECT", !builder.selects.is_empty()),
+            (
+                "WHERE",
+                !builder.conditions.is_empty() || !builder.closures.is_empty(),
+            ),
+            ("JOIN", !builder.joins.is_empty()),
+            ("GROUP BY", !builder.group_by.is_empty()),
+            ("HAVING", !builder.havings.is_empty()),
+            ("ORDER BY", !builder.order_by.is_empty()),
+            ("LIMIT", builder.limit.is_some()),
+            ("OFFSET", builder.offset.is_some()),
+        ];
+
+        if let Some((clause, _)) = clauses.iter().find(|(_, configured)| *configured) {
+            return Err(EloquentError::CannotApplyClauseOnMerge(clause.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Dialect, QueryBuilder};
+
+    #[test]
+    fn test_cannot_apply_clause_on_merge() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .merge_into("flights")
+            .using("staged_flights AS s")
+            .on("flights.id", "s.id")
+            .when_matched_update(vec![("status", "s.status")])
+            .r#where("s.status", "landed")
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnMerge(clause)) => {
+                assert_eq!(clause, "WHERE")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -24,6 +24,12 @@ impl PerformChecks for CannotApplyClauseOnUpdate {
             return Err(EloquentError::CannotApplyClauseOnUpdate("JOIN".to_string()));
         }
 
+        if !builder.returning.is_empty() {
+            return Err(EloquentError::CannotApplyClauseOnUpdate(
+                "RETURNING".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
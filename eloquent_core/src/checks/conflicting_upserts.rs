@@ -0,0 +1,54 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct ConflictingUpserts;
+
+impl PerformChecks for ConflictingUpserts {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        // on_conflict() sets the target of the on_duplicate_key_update() assignments
+        let clauses = [
+            ("UPSERT", builder.upsert.is_some()),
+            (
+                "ON DUPLICATE KEY UPDATE",
+                !builder.on_duplicate_key_updates.is_empty() && builder.conflict_target.is_none(),
+            ),
+            ("ON CONFLICT", builder.conflict_target.is_some()),
+        ]
+        .iter()
+        .filter(|(_, configured)| *configured)
+        .map(|(clause, _)| *clause)
+        .collect::<Vec<&str>>();
+
+        if clauses.len() > 1 {
+            return Err(EloquentError::ConflictingUpserts(clauses.join(", ")));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Dialect, QueryBuilder, ToSql};
+
+    #[test]
+    fn test_conflicting_upsert_and_on_conflict() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .upsert(
+                vec![("flight_number", Box::new("KL123") as Box<dyn ToSql>)],
+                vec!["flight_number"],
+                vec![],
+            )
+            .on_conflict(vec!["flight_number"])
+            .sql();
+
+        match result {
+            Err(EloquentError::ConflictingUpserts(clauses)) => {
+                assert_eq!(clauses, "UPSERT, ON CONFLICT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -7,10 +7,40 @@ pub struct DuplicatedColumns;
 impl PerformChecks for DuplicatedColumns {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
         let mut seen = HashSet::new();
+        let mut result_columns = HashSet::new();
 
-        for select in &builder.selects {
-            if !seen.insert((&select.column, &select.function)) {
-                return Err(EloquentError::DuplicatedColumnNames(select.column.clone()));
+        // NULL placeholders only pad the result, e.g. to line up the columns of a union
+        for select in builder
+            .selects
+            .iter()
+            .filter(|select| !select.is_null_placeholder())
+        {
+            let output_name = match &select.alias {
+                Some(alias) => alias.clone(),
+                None => select.format_column_name_without_alias(),
+            };
+
+            if !seen.insert(output_name.clone()) {
+                return Err(EloquentError::DuplicatedColumnNames(output_name));
+            }
+
+            // qualified columns from different tables still share their unqualified result name
+            let result_column = match (&select.alias, &select.function) {
+                (Some(alias), _) => Some(alias.as_str()),
+                (None, None) => select
+                    .column
+                    .rsplit('.')
+                    .next()
+                    .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')),
+                _ => None,
+            };
+
+            if let Some(result_column) = result_column.filter(|name| !name.is_empty()) {
+                if !result_columns.insert(result_column.to_string()) {
+                    return Err(EloquentError::AmbiguousResultColumn(
+                        result_column.to_string(),
+                    ));
+                }
             }
         }
 
@@ -36,4 +66,63 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_duplicated_alias_names() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_as("origin", "airport")
+            .select_as("destination", "airport")
+            .sql();
+
+        match result {
+            Err(EloquentError::DuplicatedColumnNames(column)) => assert_eq!(column, "airport"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_same_column_with_different_aliases() {
+        let result = QueryBuilder::new()
+            .table("tickets")
+            .select_as("price", "usd")
+            .select_as("price", "eur")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT price AS usd, price AS eur FROM tickets"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_result_column() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join("airports", "flights.origin_airport", "airports.code")
+            .select(vec!["flights.id", "airports.id"])
+            .sql();
+
+        match result {
+            Err(EloquentError::AmbiguousResultColumn(column)) => assert_eq!(column, "id"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_qualified_columns_with_aliases() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join("airports", "flights.origin_airport", "airports.code")
+            .select("flights.id")
+            .select_as("airports.id", "airport_id")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT flights.id, airports.id AS airport_id FROM flights JOIN airports ON flights.origin_airport = airports.code"
+        );
+    }
 }
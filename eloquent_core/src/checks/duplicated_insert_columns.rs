@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct DuplicatedInsertColumns;
+
+impl PerformChecks for DuplicatedInsertColumns {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let mut seen = HashSet::new();
+
+        for insert in &builder.inserts {
+            if !seen.insert(&insert.column) {
+                return Err(EloquentError::DuplicatedInsertColumn(insert.column.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_duplicated_insert_column() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert("origin_airport", "AMS")
+            .insert("destination_airport", "FRA")
+            .insert("origin_airport", "LHR")
+            .sql();
+
+        match result {
+            Err(EloquentError::DuplicatedInsertColumn(column)) => {
+                assert_eq!(column, "origin_airport")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct EmptyCase;
+
+impl PerformChecks for EmptyCase {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if let Some(alias) = builder.empty_cases.first() {
+            return Err(EloquentError::EmptyCase(alias.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_case_without_when() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_case(|case| case.else_value(0), "disruption")
+            .sql();
+
+        match result {
+            Err(EloquentError::EmptyCase(alias)) => assert_eq!(alias, "disruption"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
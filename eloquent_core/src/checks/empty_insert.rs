@@ -0,0 +1,49 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct EmptyInsert;
+
+impl PerformChecks for EmptyInsert {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if builder.empty_insert {
+            return Err(EloquentError::EmptyInsert);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, ToSql};
+
+    #[test]
+    fn test_insert_many_without_rows() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert_many(vec![])
+            .sql();
+
+        match result {
+            Err(EloquentError::EmptyInsert) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_insert_many_with_empty_row() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert_many(vec![
+                vec![("origin_airport", Box::new("AMS") as Box<dyn ToSql>)],
+                vec![],
+            ])
+            .sql();
+
+        match result {
+            Err(EloquentError::EmptyInsert) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -4,7 +4,11 @@ pub struct GroupByWithoutSelectedOrAggregateFunction;
 
 impl PerformChecks for GroupByWithoutSelectedOrAggregateFunction {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
-        for group_by in &builder.group_by {
+        for group_by in builder
+            .group_by
+            .iter()
+            .filter(|group_by| !builder.raw_group_by.contains(group_by))
+        {
             if !builder.selects.iter().any(|select| {
                 &select.format_column_name_without_alias() == group_by
                     || select
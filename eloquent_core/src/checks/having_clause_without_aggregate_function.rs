@@ -1,11 +1,39 @@
-use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+use crate::{error::EloquentError, Operator, PerformChecks, QueryBuilder};
 
 pub struct HavingClauseWithoutAggregateFunction;
 
+pub(crate) const AGGREGATE_FUNCTIONS: [&str; 6] =
+    ["COUNT(", "SUM(", "AVG(", "MIN(", "MAX(", "GROUPING("];
+
 impl PerformChecks for HavingClauseWithoutAggregateFunction {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
         for having in &builder.havings {
-            for condition in &having.conditions {
+            if having.compare_columns {
+                for condition in &having.conditions {
+                    let mut expressions = vec![condition.field.clone()];
+
+                    for value in &condition.values {
+                        expressions.push(value.to_sql()?);
+                    }
+
+                    for expression in expressions {
+                        if !is_aggregate_expression(builder, &expression) {
+                            return Err(EloquentError::HavingClauseWithoutAggregateFunction(
+                                expression,
+                            ));
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            // raw expressions are trusted as written
+            for condition in having
+                .conditions
+                .iter()
+                .filter(|condition| condition.operator != Operator::Raw)
+            {
                 if !builder.selects.iter().any(|select| {
                     (select.format_column_name_without_alias() == condition.field
                         && select.function.is_some())
@@ -22,9 +50,21 @@ impl PerformChecks for HavingClauseWithoutAggregateFunction {
     }
 }
 
+fn is_aggregate_expression(builder: &QueryBuilder, expression: &str) -> bool {
+    let uppercased = expression.to_uppercase();
+
+    AGGREGATE_FUNCTIONS
+        .iter()
+        .any(|function| uppercased.contains(function))
+        || builder
+            .selects
+            .iter()
+            .any(|select| select.function.is_some() && select.alias.as_deref() == Some(expression))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{error::EloquentError, QueryBuilder};
+    use crate::{error::EloquentError, Comparison, QueryBuilder};
 
     #[test]
     fn test_having_clause_without_aggregate_function() {
@@ -41,4 +81,74 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_having_clause_on_count_all() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_count_all()
+            .group_by("origin")
+            .having_gt("COUNT(*)", 5)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT origin, COUNT(*) FROM flights GROUP BY origin HAVING COUNT(*) > 5"
+        );
+    }
+
+    #[test]
+    fn test_having_clause_on_count_alias() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_count("*", "flights_count")
+            .group_by("origin")
+            .having_gt("flights_count", 5)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT origin, COUNT(*) AS flights_count FROM flights GROUP BY origin HAVING flights_count > 5"
+        );
+    }
+
+    #[test]
+    fn test_having_column_without_aggregate_function() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .group_by("origin")
+            .having_column(
+                "SUM(delay_in_min)",
+                Comparison::GreaterThan,
+                "flight_duration",
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::HavingClauseWithoutAggregateFunction(column)) => {
+                assert_eq!(column, "flight_duration")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_having_column_with_aggregate_alias() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_sum("delay_in_min", "total_delay")
+            .group_by("origin")
+            .having_column("total_delay", Comparison::LessThan, "SUM(flight_duration)")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT origin, SUM(delay_in_min) AS total_delay FROM flights GROUP BY origin HAVING total_delay < SUM(flight_duration)"
+        );
+    }
 }
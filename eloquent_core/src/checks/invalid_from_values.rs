@@ -0,0 +1,68 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct InvalidFromValues;
+
+impl PerformChecks for InvalidFromValues {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(from_values) = &builder.from_values else {
+            return Ok(());
+        };
+
+        if from_values.rows.is_empty() {
+            return Err(EloquentError::InvalidFromValues("no rows".to_string()));
+        }
+
+        if let Some((i, row)) = from_values
+            .rows
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != from_values.columns.len())
+        {
+            return Err(EloquentError::InvalidFromValues(format!(
+                "row {} has {} values for {} columns",
+                i + 1,
+                row.len(),
+                from_values.columns.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_from_values_without_rows() {
+        let result = QueryBuilder::new()
+            .from_values(Vec::new(), "airports", vec!["id", "code"])
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidFromValues(reason)) => assert_eq!(reason, "no rows"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_from_values_with_ragged_rows() {
+        let result = QueryBuilder::new()
+            .from_values(
+                vec![vec![Box::new(1), Box::new("AMS")], vec![Box::new(2)]],
+                "airports",
+                vec!["id", "code"],
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidFromValues(reason)) => {
+                assert_eq!(reason, "row 2 has 1 values for 2 columns")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
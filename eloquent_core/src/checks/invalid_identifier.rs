@@ -0,0 +1,339 @@
+use crate::{
+    checks::having_clause_without_aggregate_function::AGGREGATE_FUNCTIONS, error::EloquentError,
+    is_quoted, split_table_alias, Operator, OrderKind, PerformChecks, QueryBuilder,
+};
+
+pub struct InvalidIdentifier;
+
+impl PerformChecks for InvalidIdentifier {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        // collations are never quoted, so they are validated regardless of quoting
+        for collation in builder
+            .conditions
+            .iter()
+            .chain(
+                builder
+                    .closures
+                    .iter()
+                    .flat_map(|(_, conditions)| conditions),
+            )
+            .filter_map(|condition| condition.collation.as_ref())
+        {
+            if collation.contains('.') {
+                return Err(EloquentError::InvalidIdentifier(collation.clone()));
+            }
+
+            validate(collation)?;
+        }
+
+        // table function names are never quoted either
+        if let Some(function) = &builder.from_function {
+            validate(&function.name)?;
+        }
+
+        // filtered and ordered columns are never quoted, expressions belong in the raw variants
+        for condition in builder.conditions.iter().chain(
+            builder
+                .closures
+                .iter()
+                .flat_map(|(_, conditions)| conditions),
+        ) {
+            match condition.operator {
+                Operator::Raw | Operator::Exists | Operator::NotExists => (),
+                // both columns of the period are kept in the field
+                Operator::Overlaps => {
+                    for column in condition.field.split(", ") {
+                        validate(column)?;
+                    }
+                }
+                _ => validate(&condition.field)?,
+            }
+        }
+
+        for order in builder
+            .order_by
+            .iter()
+            .filter(|order| matches!(order.kind, OrderKind::Column))
+        {
+            validate_ordered(&order.column)?;
+        }
+
+        if builder.identifier_format().quote.is_some() {
+            return Ok(());
+        }
+
+        // a CTE is referenced by its bare name, so it can neither be qualified nor aliased
+        for name in builder.ctes.iter().map(|cte| &cte.name) {
+            if name.contains('.') {
+                return Err(EloquentError::InvalidIdentifier(name.clone()));
+            }
+
+            validate(name)?;
+        }
+
+        for table in builder
+            .table
+            .iter()
+            .chain(builder.joins.iter().map(|join| &join.table))
+            .chain(builder.update_from.iter().map(|from| &from.table))
+            .chain(
+                builder
+                    .merge
+                    .iter()
+                    .filter_map(|merge| merge.source.as_ref()),
+            )
+        {
+            if is_quoted(table) {
+                continue;
+            }
+
+            match split_table_alias(table) {
+                Some((name, alias)) => {
+                    validate(name)?;
+
+                    if let Some(alias) = alias {
+                        validate(alias)?;
+                    }
+                }
+                None => return Err(EloquentError::InvalidIdentifier(table.clone())),
+            }
+        }
+
+        for alias in builder
+            .selects
+            .iter()
+            .filter_map(|select| select.alias.as_ref())
+            .chain(builder.inserted_flag.iter())
+            .chain(builder.returning.iter())
+            .chain(
+                builder
+                    .from_function
+                    .iter()
+                    .flat_map(|function| &function.columns),
+            )
+        {
+            validate(alias)?;
+        }
+
+        for column in builder
+            .inserts
+            .iter()
+            .map(|insert| &insert.column)
+            .chain(builder.updates.iter().map(|update| &update.column))
+            .chain(builder.conflict_target.iter().flatten())
+            .chain(builder.upsert.iter().flat_map(|upsert| {
+                upsert
+                    .conflict_columns
+                    .iter()
+                    .chain(upsert.update_columns.iter())
+            }))
+        {
+            validate(column)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn validate(identifier: &str) -> Result<(), EloquentError> {
+    let is_valid = is_quoted(identifier)
+        || !identifier.is_empty()
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(EloquentError::InvalidIdentifier(identifier.to_string()))
+    }
+}
+
+/// An ordered column may also be an aggregate over a single column, as the ordering check allows.
+fn validate_ordered(column: &str) -> Result<(), EloquentError> {
+    let uppercased = column.to_uppercase();
+
+    let argument = AGGREGATE_FUNCTIONS
+        .iter()
+        .find(|function| uppercased.starts_with(*function))
+        .and_then(|function| column.get(function.len()..))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .map(|argument| argument.strip_prefix("DISTINCT ").unwrap_or(argument));
+
+    match argument {
+        Some("*") => Ok(()),
+        Some(argument) => {
+            validate(argument).map_err(|_| EloquentError::InvalidIdentifier(column.to_string()))
+        }
+        None => validate(column),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Comparison, QueryBuilder, Quoted, SubqueryBuilder};
+
+    #[test]
+    fn test_valid_identifier() {
+        let result = QueryBuilder::new()
+            .table("tmp_flights_2024.departures AS d")
+            .select_as("d.origin", "departure_airport")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT d.origin AS departure_airport FROM tmp_flights_2024.departures AS d"
+        );
+    }
+
+    #[test]
+    fn test_invalid_identifier() {
+        let result = QueryBuilder::new()
+            .table("flights;DROP")
+            .select("origin")
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "flights;DROP")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_identifier_with_skipped_validation() {
+        let result = QueryBuilder::new()
+            .table("flights;DROP")
+            .skip_validation()
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "flights;DROP")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_insert_column() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert("origin; --", "AMS")
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "origin; --")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_quoted_exotic_identifier() {
+        let result = QueryBuilder::new()
+            .table("tmp-flights;2024")
+            .insert("origin airport", "AMS")
+            .quote_identifiers()
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "INSERT INTO `tmp-flights;2024` (`origin airport`) VALUES ('AMS')"
+        );
+    }
+
+    #[test]
+    fn test_forced_quoted_identifier() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_as(Quoted("origin airport"), "origin_airport")
+            .select("destination")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT `origin airport` AS origin_airport, destination FROM flights"
+        );
+    }
+
+    #[test]
+    fn test_invalid_where_and_order_by_columns() {
+        let result = QueryBuilder::new()
+            .table("users")
+            .r#where("a; DROP TABLE x; --", 1)
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "a; DROP TABLE x; --")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let result = QueryBuilder::new()
+            .table("users")
+            .order_by_asc("COUNT(id); DROP TABLE x")
+            .quote_identifiers()
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "COUNT(id); DROP TABLE x")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_cte_name() {
+        let result = QueryBuilder::new()
+            .with("bad name", SubqueryBuilder::new().table("flights"))
+            .table("flights")
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => assert_eq!(identifier, "bad name"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_collation() {
+        let result = QueryBuilder::new()
+            .table("users")
+            .where_collate("name", Comparison::Equal, "José", "utf8mb4_bin; --")
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "utf8mb4_bin; --")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_table_function() {
+        let result = QueryBuilder::new()
+            .from_function("unnest(); DROP TABLE flights; --", vec![], "x")
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidIdentifier(identifier)) => {
+                assert_eq!(identifier, "unnest(); DROP TABLE flights; --")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{error::EloquentError, Action, PerformChecks, QueryBuilder};
+
+pub struct InvalidLock;
+
+impl PerformChecks for InvalidLock {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        match (&builder.lock, &builder.lock_wait) {
+            (Some(lock), _) if !matches!(builder.get_action(), Action::Select) => {
+                Err(EloquentError::InvalidLock(lock.mode.to_string()))
+            }
+            // a compound select can not be locked as a whole
+            (Some(lock), _) if !builder.unions.is_empty() || builder.shards.is_some() => {
+                Err(EloquentError::InvalidLock(lock.mode.to_string()))
+            }
+            (None, Some(wait)) => Err(EloquentError::InvalidLock(wait.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_lock_on_update() {
+        let result = QueryBuilder::new()
+            .table("seats")
+            .update("reserved", true)
+            .for_update()
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidLock(clause)) => assert_eq!(clause, "FOR UPDATE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_lock_on_union_and_shards() {
+        let union = QueryBuilder::new()
+            .table("seats")
+            .union(QueryBuilder::new().table("archived_seats"))
+            .for_update()
+            .sql();
+
+        match union {
+            Err(EloquentError::InvalidLock(clause)) => assert_eq!(clause, "FOR UPDATE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let sharded = QueryBuilder::new()
+            .table("seats")
+            .sharded(2, |shard| format!("_{}", shard))
+            .for_update()
+            .sql();
+
+        match sharded {
+            Err(EloquentError::InvalidLock(clause)) => assert_eq!(clause, "FOR UPDATE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_skip_locked_without_lock() {
+        let result = QueryBuilder::new().table("jobs").skip_locked().sql();
+
+        match result {
+            Err(EloquentError::InvalidLock(clause)) => assert_eq!(clause, "SKIP LOCKED"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{error::EloquentError, Action, PerformChecks, QueryBuilder};
+
+pub struct InvalidShards;
+
+impl PerformChecks for InvalidShards {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(shards) = &builder.shards else {
+            return Ok(());
+        };
+
+        if shards.count == 0 {
+            return Err(EloquentError::InvalidShards("no shards".to_string()));
+        }
+
+        if !matches!(builder.get_action(), Action::Select) {
+            return Err(EloquentError::InvalidShards(
+                "only a SELECT can be sharded".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_zero_shards() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .sharded(0, |shard| format!("_{}", shard))
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidShards(reason)) => assert_eq!(reason, "no shards"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sharded_delete() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("status", "cancelled")
+            .delete()
+            .sharded(2, |shard| format!("_{}", shard))
+            .sql();
+
+        match result {
+            Err(EloquentError::InvalidShards(reason)) => {
+                assert_eq!(reason, "only a SELECT can be sharded")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{error::EloquentError, JoinType, PerformChecks, QueryBuilder};
+
+pub struct JoinWithoutConditions;
+
+impl PerformChecks for JoinWithoutConditions {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        for join in &builder.joins {
+            if join.join_type != JoinType::Cross
+                && join.conditions.is_empty()
+                && join.closures.is_empty()
+                && join.left_hand.is_empty()
+                && join.right_hand.is_empty()
+            {
+                return Err(EloquentError::MissingJoinConditions(join.table.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_join_without_conditions() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join_on("airports", |join| join)
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingJoinConditions(table)) => assert_eq!(table, "airports"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
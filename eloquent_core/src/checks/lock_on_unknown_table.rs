@@ -0,0 +1,69 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct LockOnUnknownTable;
+
+impl PerformChecks for LockOnUnknownTable {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let lock = match &builder.lock {
+            Some(lock) => lock,
+            None => return Ok(()),
+        };
+
+        let known_tables: Vec<&str> = builder
+            .table
+            .iter()
+            .chain(builder.joins.iter().map(|join| &join.table))
+            .flat_map(|table| {
+                let mut names = vec![table.as_str()];
+
+                if let Some(alias) = table.split_whitespace().last() {
+                    names.push(alias);
+                }
+
+                names
+            })
+            .collect();
+
+        for table in &lock.tables {
+            if !known_tables.contains(&table.as_str()) {
+                return Err(EloquentError::LockOnUnknownTable(table.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_lock_on_unknown_table() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join("airports", "flights.origin_airport", "airports.code")
+            .for_update_of(vec!["flights", "passengers"])
+            .sql();
+
+        match result {
+            Err(EloquentError::LockOnUnknownTable(table)) => assert_eq!(table, "passengers"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_lock_on_joined_table() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join("airports", "flights.origin_airport", "airports.code")
+            .for_update_of("airports")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights JOIN airports ON flights.origin_airport = airports.code FOR UPDATE OF airports"
+        );
+    }
+}
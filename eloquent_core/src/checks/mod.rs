@@ -1,11 +1,27 @@
 pub mod cannot_apply_clause_on_delete;
 pub mod cannot_apply_clause_on_insert;
+pub mod cannot_apply_clause_on_merge;
 pub mod cannot_apply_clause_on_update;
 pub mod cannot_use_offset_limit_with_pagination;
+pub mod conflicting_upserts;
 pub mod duplicated_columns;
 pub mod duplicated_conditions;
+pub mod duplicated_insert_columns;
+pub mod empty_case;
+pub mod empty_insert;
 pub mod group_by_without_selected_or_aggregate_function;
 pub mod having_clause_without_aggregate_function;
+pub mod invalid_from_values;
+pub mod invalid_identifier;
+pub mod invalid_lock;
+pub mod invalid_shards;
+pub mod join_without_conditions;
+pub mod lock_on_unknown_table;
 pub mod missing_table;
 pub mod multiple_crud_actions;
+pub mod multiple_from_sources;
 pub mod order_by_without_selected_or_aggregate_function;
+pub mod rollup_without_group_by;
+pub mod table_with_from_subquery;
+pub mod ungroupable_expression;
+pub mod union_with_non_select;
@@ -22,6 +22,10 @@ impl PerformChecks for MultipleCrudActions {
             crud_actions += 1;
         }
 
+        if builder.merge.is_some() {
+            crud_actions += 1;
+        }
+
         if crud_actions > 1 {
             return Err(EloquentError::MultipleCrudActions);
         }
@@ -48,4 +52,19 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_insert_and_update() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert("origin", "JFK")
+            .update("destination", "AMS")
+            .sql();
+
+        match result {
+            Err(EloquentError::MultipleCrudActions) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
 }
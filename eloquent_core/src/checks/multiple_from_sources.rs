@@ -0,0 +1,45 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct MultipleFromSources;
+
+impl PerformChecks for MultipleFromSources {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let sources = [
+            ("VALUES", builder.from_values.is_some()),
+            ("FUNCTION", builder.from_function.is_some()),
+            ("SUBQUERY", builder.from_subquery.is_some()),
+        ]
+        .iter()
+        .filter(|(_, configured)| *configured)
+        .map(|(source, _)| *source)
+        .collect::<Vec<&str>>();
+
+        if sources.len() > 1 {
+            return Err(EloquentError::MultipleFromSources(sources.join(", ")));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Dialect, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_from_function_and_from_subquery() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .from_function("generate_series", vec![Box::new(1), Box::new(10)], "n")
+            .from_sub(SubqueryBuilder::new().table("departures"), "n")
+            .sql();
+
+        match result {
+            Err(EloquentError::MultipleFromSources(sources)) => {
+                assert_eq!(sources, "FUNCTION, SUBQUERY")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -1,14 +1,36 @@
-use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+use crate::{
+    checks::having_clause_without_aggregate_function::AGGREGATE_FUNCTIONS, error::EloquentError,
+    OrderKind, PerformChecks, QueryBuilder,
+};
 
 pub struct OrderByWithoutSelectedOrAggregateFunction;
 
 impl PerformChecks for OrderByWithoutSelectedOrAggregateFunction {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
-        if builder.selects.is_empty() {
+        // every column is selected by a wildcard, so any column can be ordered by
+        if builder.selects.is_empty()
+            || builder.selects.iter().any(|select| {
+                select.function.is_none() && (select.column == "*" || select.column.ends_with(".*"))
+            })
+        {
             return Ok(());
         }
 
-        for order_by in &builder.order_by {
+        for order_by in builder
+            .order_by
+            .iter()
+            .filter(|order_by| matches!(order_by.kind, OrderKind::Column))
+        {
+            let uppercased = order_by.column.to_uppercase();
+
+            // aggregates are evaluated over the groups, so they are valid without being selected
+            if AGGREGATE_FUNCTIONS
+                .iter()
+                .any(|function| uppercased.starts_with(function))
+            {
+                continue;
+            }
+
             if !builder.selects.iter().any(|select| {
                 select.format_column_name_without_alias() == order_by.column
                     || select
@@ -47,4 +69,53 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_order_by_aggregate_expression() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .group_by("origin")
+            .order_by_desc("COUNT(*)")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT origin FROM flights GROUP BY origin ORDER BY COUNT(*) DESC"
+        );
+    }
+
+    #[test]
+    fn test_order_by_any_column_with_wildcard_select() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .limit(5)
+            .order_by_desc("departed_at")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights ORDER BY departed_at DESC LIMIT 5"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("*")
+            .order_by_asc("origin")
+            .sql();
+
+        assert_eq!(result.unwrap(), "SELECT * FROM flights ORDER BY origin ASC");
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select(vec!["flights.*", "airports.city"])
+            .join("airports", "flights.origin_airport", "airports.code")
+            .order_by_asc("flights.departed_at")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT flights.*, airports.city FROM flights JOIN airports ON flights.origin_airport = airports.code ORDER BY flights.departed_at ASC"
+        );
+    }
 }
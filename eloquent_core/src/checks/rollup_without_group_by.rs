@@ -0,0 +1,33 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct RollupWithoutGroupBy;
+
+impl PerformChecks for RollupWithoutGroupBy {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if builder.rollup && builder.group_by_columns().is_empty() {
+            return Err(EloquentError::RollupWithoutGroupBy);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_rollup_without_group_by() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_count("id", "flight_count")
+            .with_rollup()
+            .sql();
+
+        match result {
+            Err(EloquentError::RollupWithoutGroupBy) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
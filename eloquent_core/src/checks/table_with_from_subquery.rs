@@ -0,0 +1,48 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct TableWithFromSubquery;
+
+impl PerformChecks for TableWithFromSubquery {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if let (Some(table), Some(subquery)) = (&builder.table, &builder.from_subquery) {
+            if table != &subquery.alias {
+                return Err(EloquentError::TableWithFromSubquery(table.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_table_before_from_subquery() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .from_sub(SubqueryBuilder::new().table("departures"), "recent")
+            .sql();
+
+        match result {
+            Err(EloquentError::TableWithFromSubquery(table)) => assert_eq!(table, "flights"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_table_after_from_subquery() {
+        let result = QueryBuilder::new()
+            .from_sub(SubqueryBuilder::new().table("departures"), "recent")
+            .table("flights")
+            .sql();
+
+        match result {
+            Err(EloquentError::TableWithFromSubquery(table)) => assert_eq!(table, "flights"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct UngroupableExpression;
+
+impl PerformChecks for UngroupableExpression {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        // the GROUP BY repeats the expression without its bound values
+        if let Some(select) = builder
+            .auto_grouped_selects()
+            .into_iter()
+            .find(|select| !select.values.is_empty())
+        {
+            return Err(EloquentError::UngroupableExpression(select.column.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_auto_group_by_expression_with_bindings() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_raw("flight_duration DIV ? AS duration_bucket", vec![30])
+            .select_count("id", "flight_count")
+            .auto_group_by()
+            .sql();
+
+        match result {
+            Err(EloquentError::UngroupableExpression(expression)) => {
+                assert_eq!(expression, "flight_duration DIV ? AS duration_bucket")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
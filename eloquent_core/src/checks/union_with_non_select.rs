@@ -0,0 +1,39 @@
+use crate::{error::EloquentError, Action, PerformChecks, QueryBuilder};
+
+pub struct UnionWithNonSelect;
+
+impl PerformChecks for UnionWithNonSelect {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if builder.unions.is_empty() {
+            return Ok(());
+        }
+
+        let is_select = |query: &QueryBuilder| matches!(query.get_action(), Action::Select);
+
+        if !is_select(builder) || !builder.unions.iter().all(|union| is_select(&union.query)) {
+            return Err(EloquentError::UnionWithNonSelect);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_union_with_non_select() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .union(QueryBuilder::new().table("flights").delete())
+            .sql();
+
+        match result {
+            Err(EloquentError::UnionWithNonSelect) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -1,75 +1,321 @@
+use std::collections::HashMap;
+
 use crate::{
     builders::{
-        delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder, update::UpdateBuilder,
+        delete::DeleteBuilder, insert::InsertBuilder, merge::MergeBuilder, select::SelectBuilder,
+        update::UpdateBuilder,
+    },
+    compilers::{
+        conditions, ctes, explain, group_by, havings, joins, limit, offset, order_by, selects,
+        tags, updates,
     },
-    compilers::{conditions, group_by, havings, joins, limit, offset, order_by, selects},
     error::EloquentError,
-    Action, Condition, Logic, QueryBuilder, SqlBuilder, SubqueryBuilder, ToSql,
+    resolve_annotations, resolve_quoted, Action, Dialect, IdentifierFormat, QueryBuilder,
+    SqlBuilder, SubqueryBuilder, ToSql, Variable,
 };
 
 pub fn build_statement(builder: &QueryBuilder) -> Result<String, EloquentError> {
+    let mut params: Vec<&dyn ToSql> = Vec::new();
+
+    let sql = resolve_annotations(&compile_statement(builder, &mut params)?, true);
+
+    let mut formatted_sql =
+        substitute_placeholders(&sql, &params, builder.identifier_format(), &mut |param| {
+            inline(builder, param)
+        })?;
+
+    tags::format(&builder.tags, &mut formatted_sql);
+
+    log::trace!(target: "eloquent", "{}", formatted_sql);
+
+    Ok(formatted_sql)
+}
+
+pub fn build_set_clause(builder: &QueryBuilder) -> Result<Option<String>, EloquentError> {
     if builder.enable_checks {
         builder.perform_checks()?;
     }
 
+    builder.validate_identifiers()?;
+
+    if !matches!(builder.get_action(), Action::Update) {
+        return Ok(None);
+    }
+
+    let mut params: Vec<&dyn ToSql> = Vec::new();
     let mut sql = String::new();
-    let mut params: Vec<&Box<dyn ToSql>> = Vec::new();
 
-    sql = match builder.get_action() {
-        Action::Select => SelectBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Insert => InsertBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Update => UpdateBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Delete => DeleteBuilder::build(builder, &mut sql, &mut params)?,
-    };
+    updates::format_set(
+        &builder.updates,
+        builder.identifier_format(),
+        &mut sql,
+        &mut params,
+    );
+
+    let sql = resolve_quoted(&sql, builder.dialect);
 
-    let formatted_sql = sql.replace('?', "{}");
+    substitute_placeholders(&sql, &params, builder.identifier_format(), &mut |param| {
+        inline(builder, param)
+    })
+    .map(Some)
+}
+
+pub fn build_prepare(
+    builder: &QueryBuilder,
+    name: &str,
+) -> Result<(String, Vec<Variable>), EloquentError> {
+    if builder.dialect != Dialect::Postgres {
+        return Err(EloquentError::UnsupportedByDialect("PREPARE".to_string()));
+    }
+
+    validate_statement_name(name)?;
+
+    let (sql, bindings) = build_statement_with_bindings(builder)?;
+
+    Ok((format!("PREPARE {} AS {}", name, sql), bindings))
+}
+
+pub fn build_execute(name: &str, arguments: &[Variable]) -> Result<String, EloquentError> {
+    validate_statement_name(name)?;
 
-    let formatted_sql = params
+    if arguments.is_empty() {
+        return Ok(format!("EXECUTE {}", name));
+    }
+
+    let arguments = arguments
         .iter()
-        .map(|p| p.as_ref().to_sql())
-        .fold(formatted_sql, |acc, val| {
-            acc.replacen("{}", &val.unwrap(), 1)
-        });
+        .map(|argument| argument.to_sql())
+        .collect::<Result<Vec<String>, EloquentError>>()?;
+
+    Ok(format!("EXECUTE {}({})", name, arguments.join(", ")))
+}
+
+pub fn build_deallocate(name: &str) -> Result<String, EloquentError> {
+    validate_statement_name(name)?;
+
+    Ok(format!("DEALLOCATE {}", name))
+}
+
+fn validate_statement_name(name: &str) -> Result<(), EloquentError> {
+    let is_valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
 
-    if formatted_sql.contains("{}") {
-        return Err(EloquentError::MissingPlaceholders);
+    match is_valid {
+        true => Ok(()),
+        false => Err(EloquentError::InvalidIdentifier(name.to_string())),
     }
+}
+
+pub fn build_statement_with_bindings(
+    builder: &QueryBuilder,
+) -> Result<(String, Vec<Variable>), EloquentError> {
+    let mut params: Vec<&dyn ToSql> = Vec::new();
+    let mut bindings = Vec::new();
+
+    let sql = resolve_annotations(&compile_statement(builder, &mut params)?, false);
+
+    let mut formatted_sql = substitute_placeholders(
+        &sql,
+        &params,
+        builder.identifier_format(),
+        &mut |param| match param.to_variable() {
+            Some(variable) => match builder.dialect {
+                Dialect::Postgres => {
+                    let position = bindings
+                        .iter()
+                        .position(|bound| builder.dedupe_bindings && *bound == variable)
+                        .unwrap_or_else(|| {
+                            bindings.push(variable);
+                            bindings.len() - 1
+                        });
+
+                    Ok(format!("${}", position + 1))
+                }
+                _ => {
+                    bindings.push(variable);
+
+                    Ok("?".to_string())
+                }
+            },
+            None => param.to_sql(),
+        },
+    )?;
+
+    tags::format(&builder.tags, &mut formatted_sql);
 
     log::trace!(target: "eloquent", "{}", formatted_sql);
 
-    Ok(formatted_sql)
+    Ok((formatted_sql, bindings))
+}
+
+pub fn build_statement_named(
+    builder: &QueryBuilder,
+) -> Result<(String, HashMap<String, Variable>), EloquentError> {
+    let mut params: Vec<&dyn ToSql> = Vec::new();
+    let mut bindings = HashMap::new();
+
+    let sql = resolve_annotations(&compile_statement(builder, &mut params)?, false);
+
+    let mut formatted_sql = substitute_placeholders(
+        &sql,
+        &params,
+        builder.identifier_format(),
+        &mut |param| match param.to_variable() {
+            Some(variable) => {
+                let name = format!("p{}", bindings.len());
+                bindings.insert(name.clone(), variable);
+                Ok(format!(":{}", name))
+            }
+            None => param.to_sql(),
+        },
+    )?;
+
+    tags::format(&builder.tags, &mut formatted_sql);
+
+    log::trace!(target: "eloquent", "{}", formatted_sql);
+
+    Ok((formatted_sql, bindings))
+}
+
+/// Render a parameter into the SQL string, unless strict bindings reject inlined values.
+fn inline(builder: &QueryBuilder, param: &dyn ToSql) -> Result<String, EloquentError> {
+    if builder.strict_bindings && param.to_variable().is_some() {
+        return Err(EloquentError::InliningDisabled);
+    }
+
+    literal(param, builder.dialect)
+}
+
+/// Render a parameter as a literal, quoting strings the way the dialect reads them.
+fn literal(param: &dyn ToSql, dialect: Dialect) -> Result<String, EloquentError> {
+    match (param.to_variable(), dialect) {
+        (Some(Variable::String(value)), _) => Ok(dialect.quote_string(&value)),
+        // SQL Server has no boolean literals
+        (Some(Variable::Bool(value)), Dialect::Mssql) => Ok(u8::from(value).to_string()),
+        _ => param.to_sql(),
+    }
+}
+
+pub(crate) fn compile_statement<'a>(
+    builder: &'a QueryBuilder,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    if builder.enable_checks {
+        builder.perform_checks()?;
+    }
+
+    builder.validate_identifiers()?;
+
+    let mut sql = String::new();
+
+    explain::format(&builder.explain, builder.dialect, &mut sql)?;
+    ctes::format(
+        &builder.ctes,
+        builder.dialect,
+        builder.identifier_format(),
+        &mut sql,
+        params,
+    )?;
+
+    let sql = match builder.get_action() {
+        Action::Select => SelectBuilder::build(builder, &mut sql, params),
+        Action::Insert => InsertBuilder::build(builder, &mut sql, params),
+        Action::Update => UpdateBuilder::build(builder, &mut sql, params),
+        Action::Delete => DeleteBuilder::build(builder, &mut sql, params),
+        Action::Merge => MergeBuilder::build(builder, &mut sql, params),
+    }?;
+
+    Ok(resolve_quoted(&sql, builder.dialect))
 }
 
 pub fn build_substatement(builder: &SubqueryBuilder) -> Result<String, EloquentError> {
+    let mut params: Vec<&dyn ToSql> = Vec::new();
+
+    let sql = compile_substatement(builder, IdentifierFormat::default(), &mut params)?;
+
+    substitute_placeholders(&sql, &params, IdentifierFormat::default(), &mut |param| {
+        literal(param, Dialect::default())
+    })
+}
+
+pub(crate) fn compile_substatement<'a>(
+    builder: &'a SubqueryBuilder,
+    format: IdentifierFormat,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    let dialect = format.dialect;
     let mut sql = String::new();
-    sql.push('(');
 
-    let mut params: Vec<&Box<dyn ToSql>> = Vec::new();
+    selects::format(
+        &format.table(builder.table.as_ref().unwrap()),
+        &builder.selects,
+        &[],
+        None,
+        format,
+        &mut sql,
+        params,
+    )?;
+    joins::format(&builder.joins, format, &mut sql, params)?;
+    conditions::format(
+        &builder.conditions,
+        &[],
+        &None,
+        &[],
+        dialect,
+        &mut sql,
+        params,
+    )?;
+    group_by::format(&builder.group_by, false, dialect, &mut sql)?;
+    havings::format(&builder.havings, dialect, &mut sql, params)?;
+    order_by::format(&builder.order_by, dialect, &mut sql, params)?;
+    limit::format(
+        &builder.limit,
+        &builder.offset,
+        &None,
+        !builder.order_by.is_empty(),
+        dialect,
+        &mut sql,
+        params,
+    );
+    offset::format(&builder.offset, dialect, &mut sql, params);
 
-    let closures: Vec<(Logic, Vec<Condition>)> = Vec::new();
+    Ok(resolve_quoted(&sql, dialect))
+}
 
-    selects::format(builder.table.as_ref().unwrap(), &builder.selects, &mut sql);
-    joins::format(&builder.joins, &mut sql);
-    conditions::format(&builder.conditions, &closures, &None, &mut sql, &mut params)?;
-    group_by::format(&builder.group_by, &mut sql);
-    havings::format(&builder.havings, &mut sql)?;
-    order_by::format(&builder.order_by, &mut sql);
-    limit::format(&builder.limit, &None, &mut sql);
-    offset::format(&builder.offset, &mut sql);
+/// Replace every `?` placeholder with the rendered parameter at the same position.
+///
+/// Subqueries are compiled in place, so their own values go through `render` in order of appearance.
+fn substitute_placeholders<F>(
+    sql: &str,
+    params: &[&dyn ToSql],
+    format: IdentifierFormat,
+    render: &mut F,
+) -> Result<String, EloquentError>
+where
+    F: FnMut(&dyn ToSql) -> Result<String, EloquentError>,
+{
+    let mut parts = sql.split('?');
+    let mut params = params.iter();
 
-    sql.push(')');
+    let mut formatted_sql = parts.next().unwrap_or_default().to_string();
 
-    let formatted_sql = sql.replace('?', "{}");
+    for part in parts {
+        let param = params.next().ok_or(EloquentError::MissingPlaceholders)?;
 
-    let formatted_sql = params
-        .iter()
-        .map(|p| p.as_ref().to_sql())
-        .fold(formatted_sql, |acc, val| {
-            acc.replacen("{}", &val.unwrap(), 1)
-        });
+        match param.compile(format) {
+            Some(compiled) => {
+                let (sql, nested_params) = compiled?;
+                formatted_sql.push_str(&substitute_placeholders(
+                    &sql,
+                    &nested_params,
+                    format,
+                    render,
+                )?);
+            }
+            None => formatted_sql.push_str(&render(*param)?),
+        }
 
-    if formatted_sql.contains("{}") {
-        return Err(EloquentError::MissingPlaceholders);
+        formatted_sql.push_str(part);
     }
 
     Ok(formatted_sql)
@@ -77,7 +323,10 @@ pub fn build_substatement(builder: &SubqueryBuilder) -> Result<String, EloquentE
 
 #[cfg(test)]
 mod tests {
-    use crate::{error::EloquentError, QueryBuilder};
+    use crate::{
+        error::EloquentError, Cast, Comparison, Dialect, Excluded, IntervalUnit, Order,
+        QueryBuilder, SubqueryBuilder, ToSql, Variable,
+    };
 
     #[test]
     fn test_missing_placeholder() {
@@ -115,4 +364,2597 @@ mod tests {
             "SELECT * FROM flights WHERE origin_airport = '\'\'N ABC \'\'S'"
         );
     }
+
+    #[test]
+    fn test_escapes_single_quotes_in_where_in() {
+        let result = QueryBuilder::new()
+            .table("passengers")
+            .where_in("last_name", vec!["O'Brien", "D'Angelo"])
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM passengers WHERE last_name IN ('O''Brien', 'D''Angelo')"
+        );
+    }
+
+    #[test]
+    fn test_escapes_single_quotes_in_insert_and_update() {
+        let insert = QueryBuilder::new()
+            .table("passengers")
+            .insert("last_name", "O'Brien".to_string())
+            .sql();
+
+        let update = QueryBuilder::new()
+            .table("passengers")
+            .update("last_name", "O'Brien")
+            .r#where("last_name", "OBrien'; DROP TABLE passengers; --")
+            .sql();
+
+        assert_eq!(
+            insert.unwrap(),
+            "INSERT INTO passengers (last_name) VALUES ('O''Brien')"
+        );
+        assert_eq!(
+            update.unwrap(),
+            "UPDATE passengers SET last_name = 'O''Brien' WHERE last_name = 'OBrien''; DROP TABLE passengers; --'"
+        );
+    }
+
+    #[test]
+    fn test_pagination_keeps_where_precedence() {
+        let query = |last_id: Option<u64>| {
+            QueryBuilder::new()
+                .table("flights")
+                .r#where("origin", "AMS")
+                .or_where("origin", "FRA")
+                .paginate("id", last_id, 25)
+        };
+
+        assert_eq!(
+            query(Some(100)).sql().unwrap(),
+            "SELECT * FROM flights WHERE id > 100 AND (origin = 'AMS' OR origin = 'FRA') LIMIT 25"
+        );
+        assert_eq!(
+            query(None).sql().unwrap(),
+            "SELECT * FROM flights WHERE origin = 'AMS' OR origin = 'FRA' LIMIT 25"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .where_gt("seats", 100)
+            .paginate("id", Some(100), 25)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE id > 100 AND origin = 'AMS' AND seats > 100 LIMIT 25"
+        );
+    }
+
+    #[test]
+    fn test_bindings_limit_offset_order() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .select("origin")
+            .select_count("id", "flight_count")
+            .having("flight_count", 5)
+            .group_by("origin")
+            .limit(10)
+            .offset(20)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, COUNT(id) AS flight_count FROM flights WHERE origin = ? GROUP BY origin HAVING flight_count = ? LIMIT ? OFFSET ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("AMS".to_string()),
+                Variable::Int(5),
+                Variable::UInt(10),
+                Variable::UInt(20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_offset_inlined_without_bindings() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .limit(10)
+            .offset(20)
+            .sql();
+
+        assert_eq!(result.unwrap(), "SELECT * FROM flights LIMIT 10 OFFSET 20");
+    }
+
+    #[test]
+    fn test_unused_bindings() {
+        let result = QueryBuilder::new()
+            .select_raw("flight_duration * ? as delay_in_min", vec![5, 60])
+            .table("flights")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnusedBindings(fragment)) => {
+                assert_eq!(fragment, "flight_duration * ? as delay_in_min")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_select_raw_bindings() {
+        let (sql, bindings) = QueryBuilder::new()
+            .select_raw("flight_duration * ? as delay_in_min", vec![5])
+            .table("flights")
+            .r#where("origin", "AMS")
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT flight_duration * ? as delay_in_min FROM flights WHERE origin = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::Int(5), Variable::String("AMS".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_use_as_keyword() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights AS f")
+                .select_as("f.origin", "departure_airport")
+                .join("airports AS a", "a.code", "f.origin")
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT f.origin AS departure_airport FROM flights AS f JOIN airports AS a ON a.code = f.origin"
+        );
+        assert_eq!(
+            query().use_as_keyword(false).sql().unwrap(),
+            "SELECT f.origin departure_airport FROM flights f JOIN airports a ON a.code = f.origin"
+        );
+    }
+
+    #[test]
+    fn test_named_bindings() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .where_in("destination", vec!["FRA", "LHR"])
+            .limit(10)
+            .sql_named()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = :p0 AND destination IN (:p1, :p2) LIMIT :p3"
+        );
+        assert_eq!(bindings.len(), 4);
+        assert_eq!(bindings["p0"], Variable::String("AMS".to_string()));
+        assert_eq!(bindings["p1"], Variable::String("FRA".to_string()));
+        assert_eq!(bindings["p2"], Variable::String("LHR".to_string()));
+        assert_eq!(bindings["p3"], Variable::UInt(10));
+    }
+
+    #[test]
+    fn test_merge_unsupported_dialect() {
+        let result = QueryBuilder::new()
+            .merge_into("flights")
+            .using("staged_flights AS s")
+            .on("flights.id", "s.id")
+            .when_matched_update(vec![("status", "s.status")])
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(clause)) => assert_eq!(clause, "MERGE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_merge_insert_counts_and_mssql_terminator() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .merge_into("flights")
+            .using("staged_flights AS s")
+            .on("flights.id", "s.id")
+            .when_not_matched_insert(vec!["id", "status"], vec!["s.id"])
+            .sql();
+
+        match result {
+            Err(EloquentError::MismatchedMergeInsert(counts)) => {
+                assert_eq!(counts, "2 columns and 1 values")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .merge_into("flights")
+            .using("staged_flights AS s")
+            .on("flights.id", "s.id")
+            .sql();
+
+        match result {
+            Err(EloquentError::IncompleteMerge(clause)) => assert_eq!(clause, "WHEN"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Mssql)
+            .merge_into("flights")
+            .using("staged_flights AS s")
+            .on("flights.id", "s.id")
+            .when_matched_update(vec![("status", "s.status")])
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "MERGE INTO flights USING staged_flights AS s ON flights.id = s.id WHEN MATCHED THEN UPDATE SET status = s.status;"
+        );
+    }
+
+    #[test]
+    fn test_array_overlaps_unsupported_dialect() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .where_array_overlaps("tags", vec!["red-eye"])
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(operator)) => assert_eq!(operator, "&&"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_where_exists_inside_closure() {
+        let subquery = SubqueryBuilder::new()
+            .table("bookings")
+            .select("id")
+            .where_column("bookings.flight_id", Comparison::Equal, "flights.id")
+            .r#where("status", "confirmed");
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .where_closure(|query| query.where_not_exists(subquery).or_where_null("gate"))
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE origin = 'AMS' AND (NOT EXISTS (SELECT id FROM bookings WHERE bookings.flight_id = flights.id AND status = 'confirmed') OR gate IS NULL)"
+        );
+    }
+
+    #[test]
+    fn test_where_raw_missing_placeholder() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .where_raw("duration_in_min BETWEEN ? AND ?", vec![60])
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingPlaceholders) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_where_raw_unused_bindings() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .where_raw("duration_in_min > ?", vec![60, 120, 180])
+            .sql();
+
+        match result {
+            Err(EloquentError::UnusedBindings(fragment)) => {
+                assert_eq!(fragment, "duration_in_min > ?")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_subquery_bindings() {
+        let subquery = SubqueryBuilder::new()
+            .table("bookings")
+            .select("flight_id")
+            .r#where("status", "O'Brien")
+            .where_gt("seats", 2);
+
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .where_in("id", vec![subquery])
+            .where_lt("duration_in_min", 120)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = ? AND id IN (SELECT flight_id FROM bookings WHERE status = ? AND seats > ?) AND duration_in_min < ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("AMS".to_string()),
+                Variable::String("O'Brien".to_string()),
+                Variable::Int(2),
+                Variable::Int(120),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_bindings_order() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .update("status", "delayed")
+            .update("delay_in_min", 15)
+            .r#where("id", 42)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "UPDATE flights SET status = ?, delay_in_min = ? WHERE id = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("delayed".to_string()),
+                Variable::Int(15),
+                Variable::Int(42),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ansi_standard() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::AnsiStandard)
+            .table("passengers AS p")
+            .select_concat(vec!["p.first_name", "' '", "p.last_name"], "full_name")
+            .join("bookings AS b", "b.passenger_id", "p.id")
+            .where_within_last("b.booked_at", 2, IntervalUnit::Weeks)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT p.first_name || ' ' || p.last_name AS \"full_name\" FROM \"passengers\" AS \"p\" JOIN \"bookings\" AS \"b\" ON b.passenger_id = p.id WHERE b.booked_at >= CURRENT_TIMESTAMP - INTERVAL '14' DAY"
+        );
+    }
+
+    #[test]
+    fn test_inserted_flag_unsupported_dialect() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .on_conflict(vec!["flight_number"])
+            .on_duplicate_key_update("status", Excluded("status"))
+            .returning_inserted_flag("inserted")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(clause)) => assert_eq!(clause, "xmax"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_postgres_numbered_bindings() {
+        let subquery = SubqueryBuilder::new()
+            .table("bookings")
+            .select("flight_id")
+            .r#where("status", "confirmed");
+
+        let (sql, bindings) = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .r#where("origin", "AMS")
+            .where_in("id", vec![subquery])
+            .limit(10)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = $1 AND id IN (SELECT flight_id FROM bookings WHERE status = $2) LIMIT $3"
+        );
+        assert_eq!(bindings.len(), 3);
+    }
+
+    #[test]
+    fn test_where_in_wide_integers() {
+        let query = || {
+            QueryBuilder::new()
+                .table("transactions")
+                .where_in("amount", vec![1i64, -2i64, 3_000_000_000i64])
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT * FROM transactions WHERE amount IN (1, -2, 3000000000)"
+        );
+
+        let (_, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(1),
+                Variable::Int(-2),
+                Variable::Int(3_000_000_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_negative_and_wide_integers() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("accounts")
+            .where_gt("balance", -100)
+            .where_lt("ledger_id", 9_000_000_000i64)
+            .where_not("branch", -7i16)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM accounts WHERE balance > ? AND ledger_id < ? AND branch != ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::from(-100),
+                Variable::from(9_000_000_000i64),
+                Variable::from(-7i16),
+            ]
+        );
+        assert_eq!(bindings[0], Variable::Int(-100));
+
+        let result = QueryBuilder::new()
+            .table("accounts")
+            .where_gt("balance", -100)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM accounts WHERE balance > -100"
+        );
+    }
+
+    #[test]
+    fn test_float_values() {
+        let result = QueryBuilder::new()
+            .table("products")
+            .where_gte("price", 9.99)
+            .where_lt("weight", 5.0)
+            .where_in("discount", vec![0.1, 0.25, 1.0])
+            .where_not("rating", 4.7f32)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM products WHERE price >= 9.99 AND weight < 5.0 AND discount IN (0.1, 0.25, 1.0) AND rating != 4.7"
+        );
+
+        let (_, bindings) = QueryBuilder::new()
+            .table("products")
+            .where_gte("price", 9.99)
+            .where_not("rating", 4.7f32)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(bindings, vec![Variable::Float(9.99), Variable::Float(4.7)]);
+    }
+
+    #[test]
+    fn test_non_finite_floats_are_not_inlined() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let result = QueryBuilder::new()
+                .table("products")
+                .where_gte("price", value)
+                .sql();
+
+            match result {
+                Err(EloquentError::NonFiniteFloat(_)) => (),
+                Err(_error) => panic!(),
+                Ok(_value) => panic!(),
+            }
+        }
+
+        let result = QueryBuilder::new()
+            .table("products")
+            .where_not("rating", f32::NAN)
+            .sql();
+
+        match result {
+            Err(EloquentError::NonFiniteFloat(value)) => assert_eq!(value, "NaN"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_wrap_and_filter_bindings() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_as("ROW_NUMBER() OVER (PARTITION BY origin)", "rn")
+            .r#where("status", "landed")
+            .wrap_and_filter("ranked", |query| query.where_lte("rn", 3))
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM (SELECT origin, ROW_NUMBER() OVER (PARTITION BY origin) AS rn FROM flights WHERE status = ?) AS ranked WHERE rn <= ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::String("landed".to_string()), Variable::Int(3)]
+        );
+    }
+
+    #[test]
+    fn test_optional_values() {
+        let gate: Option<&str> = None;
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", Some("AMS"))
+            .where_not("gate", gate)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE origin = 'AMS' AND gate IS NOT NULL"
+        );
+
+        let (sql, bindings) = QueryBuilder::new()
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .insert("gate", gate)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "INSERT INTO flights (flight_number, gate) VALUES (?, ?)"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::String("KL123".to_string()), Variable::Null]
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .update("gate", None::<&str>)
+            .update("delay_in_min", Some(15))
+            .r#where("id", 42)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "UPDATE flights SET gate = NULL, delay_in_min = 15 WHERE id = 42"
+        );
+        assert_eq!(Variable::from(Some(15)), Variable::Int(15));
+        assert_eq!(Variable::from(None::<i64>), Variable::Null);
+    }
+
+    #[test]
+    fn test_insert_many_inconsistent_columns() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert_many(vec![
+                vec![
+                    ("origin_airport", Box::new("AMS") as Box<dyn ToSql>),
+                    ("flight_duration", Box::new(90)),
+                ],
+                vec![
+                    ("origin_airport", Box::new("FRA") as Box<dyn ToSql>),
+                    ("destination_airport", Box::new("LHR")),
+                ],
+            ])
+            .sql();
+
+        match result {
+            Err(EloquentError::InconsistentInsertColumns(column)) => {
+                assert_eq!(column, "destination_airport")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_without_conflict_columns() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .table("flights")
+            .upsert(
+                vec![("flight_number", Box::new("KL123") as Box<dyn ToSql>)],
+                vec![],
+                vec!["status"],
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingConflictColumns) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_on_conflict_without_columns() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .on_conflict(vec![])
+            .on_duplicate_key_update("status", Excluded("status"))
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingConflictColumns) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_without_update_columns() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .upsert(
+                    vec![("flight_number", Box::new("KL123") as Box<dyn ToSql>)],
+                    vec!["flight_number"],
+                    vec![],
+                )
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(
+            query(Dialect::Postgres),
+            "INSERT INTO flights (flight_number) VALUES ('KL123') ON CONFLICT (flight_number) DO NOTHING"
+        );
+        assert_eq!(
+            query(Dialect::MySql),
+            "INSERT INTO flights (flight_number) VALUES ('KL123') ON DUPLICATE KEY UPDATE flight_number = flight_number"
+        );
+    }
+
+    #[test]
+    fn test_where_prefix_forms() {
+        let like = QueryBuilder::new()
+            .table("airports")
+            .where_prefix("code", "AB")
+            .sql();
+
+        let range = QueryBuilder::new()
+            .table("airports")
+            .where_prefix_range("code", "AB")
+            .sql();
+
+        assert_eq!(
+            like.unwrap(),
+            "SELECT * FROM airports WHERE code LIKE 'AB%'"
+        );
+        assert_eq!(
+            range.unwrap(),
+            "SELECT * FROM airports WHERE code >= 'AB' AND code < 'AC'"
+        );
+    }
+
+    #[test]
+    fn test_where_prefix_range_edge_cases() {
+        let dropped = QueryBuilder::new()
+            .table("airports")
+            .where_prefix_range("code", "A\u{10FFFF}")
+            .sql();
+
+        let surrogate = QueryBuilder::new()
+            .table("airports")
+            .where_prefix_range("code", "A\u{D7FF}")
+            .sql();
+
+        let unbounded = QueryBuilder::new()
+            .table("airports")
+            .where_prefix_range("code", "\u{10FFFF}")
+            .sql();
+
+        assert_eq!(
+            dropped.unwrap(),
+            "SELECT * FROM airports WHERE code >= 'A\u{10FFFF}' AND code < 'B'"
+        );
+        assert_eq!(
+            surrogate.unwrap(),
+            "SELECT * FROM airports WHERE code >= 'A\u{D7FF}' AND code < 'A\u{E000}'"
+        );
+        assert_eq!(
+            unbounded.unwrap(),
+            "SELECT * FROM airports WHERE code >= '\u{10FFFF}'"
+        );
+    }
+
+    #[test]
+    fn test_unnest_ordinality_unsupported_dialect() {
+        let result = QueryBuilder::new()
+            .from_unnest_ordinality("stops", "s", "airport", "position")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(clause)) => {
+                assert_eq!(clause, "WITH ORDINALITY")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_increment_bindings() {
+        let (sql, bindings) = QueryBuilder::new()
+            .table("articles")
+            .increment_by("views", 2)
+            .update("title", "Eloquent")
+            .decrement("credits")
+            .r#where("id", 7)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "UPDATE articles SET views = views + ?, title = ?, credits = credits - ? WHERE id = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(2),
+                Variable::String("Eloquent".to_string()),
+                Variable::Int(1),
+                Variable::Int(7),
+            ]
+        );
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .quote_identifiers()
+            .table("articles")
+            .increment("views")
+            .r#where("id", 7)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "UPDATE \"articles\" SET \"views\" = \"views\" + 1 WHERE id = 7"
+        );
+    }
+
+    #[test]
+    fn test_union_binds_values_in_order() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .r#where("status", "landed")
+            .union(
+                QueryBuilder::new()
+                    .table("archived_flights")
+                    .select("origin")
+                    .r#where("year", 2024),
+            )
+            .order_by_asc("origin")
+            .limit(5);
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin FROM flights WHERE status = ? UNION (SELECT origin FROM archived_flights WHERE year = ?) ORDER BY origin ASC LIMIT ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("landed".to_string()),
+                Variable::Int(2024),
+                Variable::UInt(5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union_on_sqlite_without_parentheses() {
+        let query = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .table("flights")
+            .select("origin")
+            .union_all(
+                QueryBuilder::new()
+                    .table("archived_flights")
+                    .select("origin"),
+            );
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT origin FROM flights UNION ALL SELECT origin FROM archived_flights"
+        );
+    }
+
+    #[test]
+    fn test_distinct_window_aggregate_is_gated_by_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .select("origin")
+                .select_count_distinct_over("destination", vec!["origin", "airline"], "routes")
+                .sql()
+        };
+
+        assert_eq!(
+            query(Dialect::AnsiStandard).unwrap(),
+            "SELECT origin, COUNT(DISTINCT destination) OVER (PARTITION BY origin, airline) AS \"routes\" FROM \"flights\""
+        );
+
+        for dialect in [
+            Dialect::MySql,
+            Dialect::Postgres,
+            Dialect::Sqlite,
+            Dialect::Mssql,
+        ] {
+            match query(dialect) {
+                Err(EloquentError::UnsupportedByDialect(feature)) => {
+                    assert_eq!(feature, "DISTINCT window aggregate")
+                }
+                Err(_error) => panic!(),
+                Ok(_value) => panic!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_limit() {
+        let query = QueryBuilder::new().table("flights").default_limit(100);
+
+        assert_eq!(query.sql().unwrap(), "SELECT * FROM flights LIMIT 100");
+
+        let query = QueryBuilder::new()
+            .table("flights")
+            .limit(10)
+            .default_limit(100);
+
+        assert_eq!(query.sql().unwrap(), "SELECT * FROM flights LIMIT 10");
+
+        let query = QueryBuilder::new()
+            .table("flights")
+            .paginate::<u64>("id", None, 25)
+            .default_limit(100);
+
+        assert_eq!(query.sql().unwrap(), "SELECT * FROM flights LIMIT 25");
+    }
+
+    #[test]
+    fn test_multiple_ctes_bind_before_the_statement() {
+        let query = QueryBuilder::new()
+            .with(
+                "delayed",
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .select("flight_number")
+                    .where_gt("delay_minutes", 30),
+            )
+            .with(
+                "crew",
+                SubqueryBuilder::new()
+                    .table("assignments")
+                    .select(vec!["flight_number", "pilot"])
+                    .r#where("role", "captain"),
+            )
+            .table("delayed")
+            .join("crew", "crew.flight_number", "delayed.flight_number")
+            .select("crew.pilot")
+            .r#where("delayed.flight_number", "KL1000");
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "WITH delayed AS (SELECT flight_number FROM flights WHERE delay_minutes > ?), crew AS (SELECT flight_number, pilot FROM assignments WHERE role = ?) SELECT crew.pilot FROM delayed JOIN crew ON crew.flight_number = delayed.flight_number WHERE delayed.flight_number = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(30),
+                Variable::String("captain".to_string()),
+                Variable::String("KL1000".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recursive_cte_keyword_by_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .with(
+                    "delayed",
+                    SubqueryBuilder::new().table("flights").select("id"),
+                )
+                .with_recursive(
+                    "routes",
+                    SubqueryBuilder::new().table("connections").select("id"),
+                )
+                .table("routes")
+                .select("id")
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(
+            query(Dialect::Postgres),
+            "WITH RECURSIVE delayed AS (SELECT id FROM flights), routes AS (SELECT id FROM connections) SELECT id FROM routes"
+        );
+        assert_eq!(
+            query(Dialect::Mssql),
+            "WITH delayed AS (SELECT id FROM flights), routes AS (SELECT id FROM connections) SELECT id FROM routes"
+        );
+    }
+
+    #[test]
+    fn test_from_sub_binds_between_selects_and_conditions() {
+        let query = QueryBuilder::new()
+            .from_sub(
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .select("origin")
+                    .r#where("status", "delayed"),
+                "delayed",
+            )
+            .select_raw("COALESCE(origin, ?) AS origin", vec!["unknown"])
+            .r#where("origin", "AMS");
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT COALESCE(origin, ?) AS origin FROM (SELECT origin FROM flights WHERE status = ?) AS delayed WHERE origin = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("unknown".to_string()),
+                Variable::String("delayed".to_string()),
+                Variable::String("AMS".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nulls_ordering_in_every_output() {
+        use crate::{Nulls, Order};
+
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("flights")
+                .r#where("origin", "AMS")
+                .order_by_nulls("arrived_at", Order::Desc, Nulls::Last)
+                .order_by_asc("flight_number")
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT * FROM flights WHERE origin = 'AMS' ORDER BY arrived_at DESC NULLS LAST, flight_number ASC"
+        );
+
+        let (sql, _) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = $1 ORDER BY arrived_at DESC NULLS LAST, flight_number ASC"
+        );
+
+        assert_eq!(
+            query().pretty_sql().unwrap(),
+            "SELECT\n    *\nFROM\n    flights\nWHERE\n    origin = 'AMS'\nORDER BY\n    arrived_at DESC NULLS LAST,\n    flight_number ASC"
+        );
+    }
+
+    #[test]
+    fn test_where_in_set_by_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .where_in_set("amenities", "50%_off")
+                .sql_with_bindings()
+                .unwrap()
+        };
+
+        let (sql, bindings) = query(Dialect::MySql);
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE FIND_IN_SET(?, amenities) > 0"
+        );
+        assert_eq!(bindings, vec![Variable::String("50%_off".to_string())]);
+
+        let (sql, bindings) = query(Dialect::Sqlite);
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE INSTR(',' || amenities || ',', ',' || ? || ',') > 0"
+        );
+        assert_eq!(bindings, vec![Variable::String("50%_off".to_string())]);
+
+        let (sql, _) = query(Dialect::Postgres);
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE POSITION(',' || $1 || ',' IN ',' || amenities || ',') > 0"
+        );
+
+        let (sql, _) = query(Dialect::Mssql);
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE CHARINDEX(CONCAT(',', ?, ','), CONCAT(',', amenities, ',')) > 0"
+        );
+    }
+
+    #[test]
+    fn test_row_locks_follow_limit_and_offset() {
+        let query = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("jobs")
+            .r#where("status", "pending")
+            .limit(10)
+            .offset(20)
+            .for_share()
+            .no_wait();
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT * FROM jobs WHERE status = 'pending' LIMIT 10 OFFSET 20 FOR SHARE NOWAIT"
+        );
+
+        let query = QueryBuilder::new().table("jobs").for_share().skip_locked();
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT * FROM jobs FOR SHARE SKIP LOCKED"
+        );
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .table("jobs")
+            .for_update()
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(clause)) => assert_eq!(clause, "FOR UPDATE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_explain_options_validation() {
+        use crate::{ExplainFormat, ExplainOptions};
+
+        let query = |dialect, options| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .explain_options(options)
+                .sql()
+        };
+
+        assert_eq!(
+            query(
+                Dialect::Postgres,
+                ExplainOptions {
+                    analyze: true,
+                    buffers: false,
+                    format: Some(ExplainFormat::Yaml),
+                }
+            )
+            .unwrap(),
+            "EXPLAIN (ANALYZE, FORMAT YAML) SELECT * FROM flights"
+        );
+
+        match query(
+            Dialect::Postgres,
+            ExplainOptions {
+                analyze: false,
+                buffers: true,
+                format: None,
+            },
+        ) {
+            Err(EloquentError::InvalidExplainOptions(reason)) => {
+                assert_eq!(reason, "BUFFERS requires ANALYZE")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match query(
+            Dialect::MySql,
+            ExplainOptions {
+                analyze: true,
+                buffers: false,
+                format: None,
+            },
+        ) {
+            Err(EloquentError::UnsupportedByDialect(clause)) => {
+                assert_eq!(clause, "EXPLAIN options")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_order_by_raw_binds_after_conditions() {
+        let query = QueryBuilder::new()
+            .table("tickets")
+            .select("id")
+            .r#where("team", "support")
+            .order_by_raw("CASE WHEN priority = ? THEN 0 ELSE 1 END", vec!["urgent"])
+            .order_by_asc("id")
+            .limit(10);
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT id FROM tickets WHERE team = ? ORDER BY CASE WHEN priority = ? THEN 0 ELSE 1 END, id ASC LIMIT ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("support".to_string()),
+                Variable::String("urgent".to_string()),
+                Variable::UInt(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_sql_matches_the_update_statement() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .update("status", "delayed")
+            .increment_by("delay_minutes", 15)
+            .r#where("id", 1);
+
+        let set = query.set_sql().unwrap().unwrap();
+        let sql = query.sql().unwrap();
+
+        assert_eq!(
+            set,
+            "SET status = 'delayed', delay_minutes = delay_minutes + 15"
+        );
+        assert_eq!(sql, format!("UPDATE flights {} WHERE id = 1", set));
+
+        let query = QueryBuilder::new().table("flights").r#where("id", 1);
+
+        assert_eq!(query.set_sql().unwrap(), None);
+    }
+
+    #[test]
+    fn test_recursive_cte_for_employee_hierarchy() {
+        let anchor = SubqueryBuilder::new()
+            .table("employees")
+            .select("id")
+            .select("manager_id")
+            .select_raw("? AS depth", vec![1])
+            .r#where("id", 7);
+
+        let recursive = SubqueryBuilder::new()
+            .table("employees")
+            .join("chain", "chain.manager_id", "employees.id")
+            .select("employees.id")
+            .select("employees.manager_id")
+            .select_raw("chain.depth + ? AS depth", vec![1])
+            .where_lt("chain.depth", 10);
+
+        let query = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .with_recursive_union("chain", anchor, recursive)
+            .table("chain")
+            .select(vec!["id", "depth"])
+            .order_by_asc("depth");
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "WITH RECURSIVE chain AS (SELECT id, manager_id, ? AS depth FROM employees WHERE id = ? UNION ALL SELECT employees.id, employees.manager_id, chain.depth + ? AS depth FROM employees JOIN chain ON chain.manager_id = employees.id WHERE chain.depth < ?) SELECT id, depth FROM chain ORDER BY depth ASC"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(1),
+                Variable::Int(7),
+                Variable::Int(1),
+                Variable::Int(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_random_skips_selected_column_check() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("passengers")
+                .select("name")
+                .order_by_random()
+                .limit(5)
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(
+            query(Dialect::Sqlite),
+            "SELECT name FROM passengers ORDER BY RANDOM() LIMIT 5"
+        );
+        assert_eq!(
+            query(Dialect::MySql),
+            "SELECT name FROM passengers ORDER BY RAND() LIMIT 5"
+        );
+    }
+
+    #[test]
+    fn test_where_within_distance_by_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("airports")
+                .select("code")
+                .where_within_distance("location", 4.76, 52.31, 5000.0)
+        };
+
+        let (sql, bindings) = query(Dialect::Postgres).sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT code FROM airports WHERE ST_DWithin(location::geography, ST_SetSRID(ST_MakePoint($1, $2), 4326)::geography, $3)"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Float(4.76),
+                Variable::Float(52.31),
+                Variable::Float(5000.0)
+            ]
+        );
+
+        match query(Dialect::Sqlite).sql() {
+            Err(EloquentError::UnsupportedByDialect(function)) => {
+                assert_eq!(function, "ST_DWithin")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_for_page_clamps_to_the_first_page() {
+        let query = |page| {
+            QueryBuilder::new()
+                .table("flights")
+                .for_page(page, 20)
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(query(0), "SELECT * FROM flights LIMIT 20 OFFSET 0");
+        assert_eq!(query(1), "SELECT * FROM flights LIMIT 20 OFFSET 0");
+        assert_eq!(query(2), "SELECT * FROM flights LIMIT 20 OFFSET 20");
+    }
+
+    #[test]
+    fn test_boolean_false_is_not_rendered_as_null() {
+        let query = QueryBuilder::new()
+            .table("members")
+            .where_null("active")
+            .or_where("active", false)
+            .or_where("active", None::<bool>);
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT * FROM members WHERE active IS NULL OR active = false OR active IS NULL"
+        );
+
+        let (sql, bindings) = QueryBuilder::new()
+            .table("members")
+            .insert("active", false)
+            .insert("verified", None::<bool>)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(sql, "INSERT INTO members (active, verified) VALUES (?, ?)");
+        assert_eq!(bindings, vec![Variable::Bool(false), Variable::Null]);
+    }
+
+    #[test]
+    fn test_having_clauses_are_joined_by_their_logic() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .select_count("id", "flight_count")
+            .select_avg("delay_minutes", "avg_delay")
+            .group_by("origin_airport")
+            .having_between("avg_delay", 10, 30)
+            .having_not_in("flight_count", vec![1, 2])
+            .or_having("flight_count", 500);
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin_airport, COUNT(id) AS flight_count, AVG(delay_minutes) AS avg_delay FROM flights GROUP BY origin_airport HAVING avg_delay BETWEEN ? AND ? AND flight_count NOT IN (?, ?) OR flight_count = ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(10),
+                Variable::Int(30),
+                Variable::Int(1),
+                Variable::Int(2),
+                Variable::Int(500)
+            ]
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .group_by("origin_airport")
+            .having_in("delay_minutes", vec![1, 2])
+            .sql();
+
+        match result {
+            Err(EloquentError::HavingClauseWithoutAggregateFunction(column)) => {
+                assert_eq!(column, "delay_minutes")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_add_conditions_built_programmatically() {
+        use crate::Condition;
+
+        let filters = [("status", "delayed"), ("gate", "B12")];
+
+        let conditions = filters
+            .iter()
+            .map(|(field, value)| Condition::and(field, Comparison::Equal, value.to_string()))
+            .collect();
+
+        let query = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin", "AMS")
+            .add_conditions(conditions)
+            .add_conditions(vec![Condition::or(
+                "cancelled_at",
+                Comparison::Equal,
+                None::<String>,
+            )]);
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = ? AND status = ? AND gate = ? OR cancelled_at IS NULL"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("AMS".to_string()),
+                Variable::String("delayed".to_string()),
+                Variable::String("B12".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_having_raw_with_bindings() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .select_count("id", "flight_count")
+            .group_by("origin_airport")
+            .having_raw("SUM(delay_minutes) BETWEEN ? AND ?", vec![60, 600])
+            .having_gt("flight_count", 10);
+
+        let (sql, bindings) = query.sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin_airport, COUNT(id) AS flight_count FROM flights GROUP BY origin_airport HAVING SUM(delay_minutes) BETWEEN ? AND ? AND flight_count > ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::Int(60), Variable::Int(600), Variable::Int(10)]
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .group_by("origin_airport")
+            .having_raw("COUNT(*) > ?", vec![5, 6])
+            .sql();
+
+        match result {
+            Err(EloquentError::UnusedBindings(expression)) => {
+                assert_eq!(expression, "COUNT(*) > ?")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_where_overlaps_date_range() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("bookings")
+                .select("id")
+                .r#where("room_id", 12)
+                .where_overlaps("starts_on", "ends_on", "2024-07-01", "2024-07-14")
+        };
+
+        let (sql, bindings) = query(Dialect::Postgres).sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT id FROM bookings WHERE room_id = $1 AND (starts_on, ends_on) OVERLAPS ($2, $3)"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(12),
+                Variable::String("2024-07-01".to_string()),
+                Variable::String("2024-07-14".to_string())
+            ]
+        );
+
+        match query(Dialect::MySql).sql() {
+            Err(EloquentError::UnsupportedByDialect(operator)) => {
+                assert_eq!(operator, "OVERLAPS")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_group_by_raw_keeps_source_order() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_raw("EXTRACT(YEAR FROM departed_at) AS year", Vec::<i32>::new())
+            .select_count("id", "flight_count")
+            .group_by_raw("EXTRACT(YEAR FROM departed_at)")
+            .group_by("origin");
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT origin, EXTRACT(YEAR FROM departed_at) AS year, COUNT(id) AS flight_count FROM flights GROUP BY EXTRACT(YEAR FROM departed_at), origin"
+        );
+    }
+
+    #[test]
+    fn test_prepare_and_execute_one_query() {
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("passengers")
+                .select("name")
+                .r#where("flight_id", 42)
+                .r#where("checked_in", true)
+                .where_not_null("seat")
+                .r#where("name", "O'Brien")
+        };
+
+        let (sql, bindings) = query().prepare("passengers_on_flight").unwrap();
+
+        assert_eq!(
+            sql,
+            "PREPARE passengers_on_flight AS SELECT name FROM passengers WHERE flight_id = $1 AND checked_in = $2 AND seat IS NOT NULL AND name = $3"
+        );
+        assert_eq!(
+            QueryBuilder::execute_sql("passengers_on_flight", &bindings).unwrap(),
+            "EXECUTE passengers_on_flight(42, true, 'O''Brien')"
+        );
+        assert_eq!(
+            QueryBuilder::execute_sql("passengers_on_flight", &[]).unwrap(),
+            "EXECUTE passengers_on_flight"
+        );
+
+        match query().prepare("passengers; DROP TABLE passengers") {
+            Err(EloquentError::InvalidIdentifier(_)) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match QueryBuilder::new().table("passengers").prepare("p") {
+            Err(EloquentError::UnsupportedByDialect(statement)) => assert_eq!(statement, "PREPARE"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_select_null_lines_up_union_columns() {
+        let result = QueryBuilder::new()
+            .table("passengers")
+            .select(vec!["id", "first_name"])
+            .select_null("middle_name")
+            .select_null("nickname")
+            .union_all(
+                QueryBuilder::new()
+                    .table("crew_members")
+                    .select(vec!["id", "first_name", "middle_name"])
+                    .select_null("nickname"),
+            )
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            result,
+            "SELECT id, first_name, NULL AS middle_name, NULL AS nickname FROM passengers UNION ALL (SELECT id, first_name, middle_name, NULL AS nickname FROM crew_members)"
+        );
+    }
+
+    #[test]
+    fn test_strict_bindings_only_allow_parameterized_output() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .select("origin")
+                .select_count("id", "flight_count")
+                .r#where("status", "delayed")
+                .where_in("origin", vec!["AMS", "LHR"])
+                .group_by("origin")
+                .having_gt("flight_count", 5)
+                .strict_bindings()
+        };
+
+        match query().sql() {
+            Err(EloquentError::InliningDisabled) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match query().pretty_sql() {
+            Err(EloquentError::InliningDisabled) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, COUNT(id) AS flight_count FROM flights WHERE status = ? AND origin IN (?, ?) GROUP BY origin HAVING flight_count > ?"
+        );
+        assert_eq!(bindings.len(), 4);
+
+        let (sql, _) = query().sql_named().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, COUNT(id) AS flight_count FROM flights WHERE status = :p0 AND origin IN (:p1, :p2) GROUP BY origin HAVING flight_count > :p3"
+        );
+
+        // queries without values still compile to a plain string
+        assert_eq!(
+            QueryBuilder::new()
+                .table("flights")
+                .select("origin")
+                .strict_bindings()
+                .sql()
+                .unwrap(),
+            "SELECT origin FROM flights"
+        );
+    }
+
+    #[test]
+    fn test_select_case_binds_operands_and_results() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .select("status")
+                .select_case(|case| case.when("status", "delayed", true), "is_delayed")
+                .select_count("id", "flight_count")
+                .group_by(vec!["status", "is_delayed"])
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT status, CASE WHEN status = 'delayed' THEN true END AS is_delayed, COUNT(id) AS flight_count FROM flights GROUP BY status, is_delayed"
+        );
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT status, CASE WHEN status = ? THEN ? END AS is_delayed, COUNT(id) AS flight_count FROM flights GROUP BY status, is_delayed"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("delayed".to_string()),
+                Variable::Bool(true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_select_group_concat_per_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .select("origin")
+                .select_group_concat("destination", "; ", "destinations")
+                .group_by("origin")
+                .having_raw("COUNT(*) > ?", vec![1])
+                .sql()
+        };
+
+        assert_eq!(
+            query(Dialect::Sqlite).unwrap(),
+            "SELECT origin, GROUP_CONCAT(destination, '; ') AS destinations FROM flights GROUP BY origin HAVING COUNT(*) > 1"
+        );
+        assert_eq!(
+            query(Dialect::Mssql).unwrap(),
+            "SELECT origin, STRING_AGG(destination, '; ') AS destinations FROM flights GROUP BY origin HAVING COUNT(*) > 1"
+        );
+        assert_eq!(
+            query(Dialect::AnsiStandard).unwrap(),
+            "SELECT origin, LISTAGG(destination, '; ') WITHIN GROUP (ORDER BY destination) AS \"destinations\" FROM \"flights\" GROUP BY origin HAVING COUNT(*) > 1"
+        );
+
+        let (sql, bindings) = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .select("origin")
+            .select_group_concat("destination", "; ", "destinations")
+            .group_by("origin")
+            .having_raw("COUNT(*) > ?", vec![1])
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, STRING_AGG(destination, $1) AS destinations FROM flights GROUP BY origin HAVING COUNT(*) > $2"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::String("; ".to_string()), Variable::Int(1)]
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_group_concat("destination", "O'", "destinations")
+            .group_by("origin")
+            .having_not("destinations", 0)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT origin, GROUP_CONCAT(destination SEPARATOR 'O''') AS destinations FROM flights GROUP BY origin HAVING destinations != 0"
+        );
+
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .select("origin")
+                .select_group_concat("destination", "?\\", "destinations")
+                .r#where("status", "landed")
+                .group_by("origin")
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT origin, GROUP_CONCAT(destination SEPARATOR '?\\\\') AS destinations FROM flights WHERE status = 'landed' GROUP BY origin"
+        );
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, GROUP_CONCAT(destination SEPARATOR '?\\\\') AS destinations FROM flights WHERE status = ? GROUP BY origin"
+        );
+        assert_eq!(bindings, vec![Variable::String("landed".to_string())]);
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Sqlite)
+            .table("flights")
+            .select("origin")
+            .select_group_concat_distinct("destination", ", ", "destinations")
+            .group_by("origin")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature)) => {
+                assert_eq!(feature, "DISTINCT GROUP_CONCAT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_returning_emulated_on_mysql() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .insert("flight_number", "KL123")
+                .insert("status", "scheduled")
+                .returning("id")
+        };
+
+        assert_eq!(
+            query().multiple_statements().sql().unwrap(),
+            "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'scheduled'); SELECT LAST_INSERT_ID() AS id"
+        );
+
+        let (sql, bindings) = query().multiple_statements().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "INSERT INTO flights (flight_number, status) VALUES (?, ?); SELECT LAST_INSERT_ID() AS id"
+        );
+        assert_eq!(bindings.len(), 2);
+
+        // a single statement cannot return the inserted id
+        match query().sql() {
+            Err(EloquentError::UnsupportedByDialect(clause)) => assert_eq!(clause, "RETURNING"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        // LAST_INSERT_ID() only reads back the auto increment column
+        match query().returning("created_at").multiple_statements().sql() {
+            Err(EloquentError::UnsupportedByDialect(clause)) => assert_eq!(clause, "RETURNING"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        assert_eq!(
+            query().dialect(Dialect::Sqlite).sql().unwrap(),
+            "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'scheduled') RETURNING id"
+        );
+    }
+
+    #[test]
+    fn test_dedupe_bindings_reuses_placeholders() {
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("flights")
+                .r#where("origin", "AMS")
+                .where_not("destination", "AMS")
+                .where_gt("duration_in_min", 120)
+                .where_lt("delay_in_min", 120)
+        };
+
+        let (sql, bindings) = query().dedupe_bindings(true).sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = $1 AND destination != $1 AND duration_in_min > $2 AND delay_in_min < $2"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::String("AMS".to_string()), Variable::Int(120)]
+        );
+
+        let (sql, bindings) = query()
+            .r#where("gate", "AMS")
+            .dedupe_bindings(false)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = $1 AND destination != $2 AND duration_in_min > $3 AND delay_in_min < $4 AND gate = $5"
+        );
+        assert_eq!(bindings.len(), 5);
+
+        // positional placeholders are bound in order, so their values are never shared
+        let (sql, bindings) = query()
+            .dialect(Dialect::MySql)
+            .dedupe_bindings(true)
+            .sql_with_bindings()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin = ? AND destination != ? AND duration_in_min > ? AND delay_in_min < ?"
+        );
+        assert_eq!(bindings.len(), 4);
+    }
+
+    #[test]
+    fn test_select_window_ranks_within_partitions() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("employees")
+            .select(vec!["department", "name"])
+            .select_window(
+                "RANK()",
+                vec!["department", "office"],
+                vec![("salary", Order::Desc), ("hired_at", Order::Asc)],
+                "salary_rank",
+            )
+            .select_window("COUNT(*)", vec![], vec![], "headcount")
+            .r#where("active", true)
+            .order_by_asc("salary_rank")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT department, name, RANK() OVER (PARTITION BY department, office ORDER BY salary DESC, hired_at ASC) AS salary_rank, COUNT(*) OVER () AS headcount FROM employees WHERE active = true ORDER BY salary_rank ASC"
+        );
+    }
+
+    #[test]
+    fn test_join_two_ctes() {
+        let query = || {
+            QueryBuilder::new()
+                .with(
+                    "departures",
+                    SubqueryBuilder::new()
+                        .table("flights")
+                        .select("origin")
+                        .select_count("id", "departure_count")
+                        .group_by("origin"),
+                )
+                .with(
+                    "arrivals",
+                    SubqueryBuilder::new()
+                        .table("flights")
+                        .select("destination")
+                        .select_count("id", "arrival_count")
+                        .group_by("destination"),
+                )
+                .table("departures")
+                .select(vec![
+                    "departures.origin",
+                    "departures.departure_count",
+                    "arrivals.arrival_count",
+                ])
+                .join("arrivals", "departures.origin", "arrivals.destination")
+                .where_gt("arrivals.arrival_count", 10)
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "WITH departures AS (SELECT origin, COUNT(id) AS departure_count FROM flights GROUP BY origin), arrivals AS (SELECT destination, COUNT(id) AS arrival_count FROM flights GROUP BY destination) SELECT departures.origin, departures.departure_count, arrivals.arrival_count FROM departures JOIN arrivals ON departures.origin = arrivals.destination WHERE arrivals.arrival_count > 10"
+        );
+
+        let (sql, _) = query()
+            .dialect(Dialect::Postgres)
+            .quote_identifiers()
+            .sql_with_bindings()
+            .unwrap();
+
+        assert!(sql.starts_with("WITH \"departures\" AS (SELECT origin"));
+        assert!(sql.contains(
+            "FROM \"departures\" JOIN \"arrivals\" ON departures.origin = arrivals.destination WHERE"
+        ));
+    }
+
+    #[test]
+    fn test_cross_join_rejected_on_update_and_delete() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .cross_join("seat_classes")
+            .update("status", "boarding")
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnUpdate(clause)) => assert_eq!(clause, "JOIN"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .cross_join("seat_classes")
+            .delete()
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnDelete(clause)) => assert_eq!(clause, "JOIN"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where(
+                "flights.id",
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .select("flights.id")
+                    .cross_join("seat_classes")
+                    .where_column("seat_classes.name", Comparison::Equal, "flights.cabin"),
+            )
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE flights.id = (SELECT flights.id FROM flights CROSS JOIN seat_classes WHERE seat_classes.name = flights.cabin)"
+        );
+    }
+
+    #[test]
+    fn test_join_on_binds_values_between_select_and_where() {
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("flights")
+                .select("flights.flight_number")
+                .select_raw("flights.delay_in_min * ? AS delay_in_sec", vec![60])
+                .join_on("airports", |join| {
+                    join.on_column("flights.destination", Comparison::Equal, "airports.code")
+                        .on_closure(|group| {
+                            group
+                                .on_value("airports.country", Comparison::Equal, "NL")
+                                .or_on_value("airports.hub", Comparison::Equal, true)
+                        })
+                })
+                .r#where("flights.status", "delayed")
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT flights.flight_number, flights.delay_in_min * 60 AS delay_in_sec FROM flights JOIN airports ON flights.destination = airports.code AND (airports.country = 'NL' OR airports.hub = true) WHERE flights.status = 'delayed'"
+        );
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT flights.flight_number, flights.delay_in_min * $1 AS delay_in_sec FROM flights JOIN airports ON flights.destination = airports.code AND (airports.country = $2 OR airports.hub = $3) WHERE flights.status = $4"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::Int(60),
+                Variable::String("NL".to_string()),
+                Variable::Bool(true),
+                Variable::String("delayed".to_string()),
+            ]
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join_on("gates", |join| {
+                join.on_column("flights.gate_id", Comparison::Equal, "gates.id")
+                    .on_value("gates.closed_at", Comparison::Equal, None::<String>)
+            })
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights JOIN gates ON flights.gate_id = gates.id AND gates.closed_at IS NULL"
+        );
+    }
+
+    #[test]
+    fn test_annotate_comments_only_when_enabled() {
+        let query = |annotate| {
+            QueryBuilder::new()
+                .table("flights")
+                .select("origin")
+                .select_count("id", "flight_count")
+                .join("airports", "flights.origin", "airports.code")
+                .r#where("airports.country", "NL")
+                .group_by("origin")
+                .having_gt("flight_count", 5)
+                .order_by_desc("flight_count")
+                .limit(10)
+                .annotate(annotate)
+        };
+
+        assert_eq!(
+            query(true).sql().unwrap(),
+            "SELECT origin, COUNT(id) AS flight_count FROM flights\n-- joins\nJOIN airports ON flights.origin = airports.code\n-- filters\nWHERE airports.country = 'NL'\n-- grouping\nGROUP BY origin\n-- group filters\nHAVING flight_count > 5\n-- ordering\nORDER BY flight_count DESC\n-- pagination\nLIMIT 10"
+        );
+        assert_eq!(
+            query(false).sql().unwrap(),
+            "SELECT origin, COUNT(id) AS flight_count FROM flights JOIN airports ON flights.origin = airports.code WHERE airports.country = 'NL' GROUP BY origin HAVING flight_count > 5 ORDER BY flight_count DESC LIMIT 10"
+        );
+
+        let (sql, _) = query(true).sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin, COUNT(id) AS flight_count FROM flights JOIN airports ON flights.origin = airports.code WHERE airports.country = ? GROUP BY origin HAVING flight_count > ? ORDER BY flight_count DESC LIMIT ?"
+        );
+
+        let (sql, _) = query(true).sql_named().unwrap();
+
+        assert!(!sql.contains("--"));
+
+        assert_eq!(
+            QueryBuilder::new()
+                .table("flights")
+                .update("status", "cancelled")
+                .r#where("id", 1)
+                .annotate(true)
+                .sql()
+                .unwrap(),
+            "UPDATE flights SET status = 'cancelled'\n-- filters\nWHERE id = 1"
+        );
+    }
+
+    #[test]
+    fn test_join_sub_binds_subquery_values_in_order() {
+        let latest = || {
+            SubqueryBuilder::new()
+                .table("departures")
+                .select("flight_id")
+                .select_max("departed_at", "last_departure")
+                .where_gt("gate", 10)
+                .group_by("flight_id")
+        };
+
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("flights")
+                .select(vec!["flights.flight_number", "latest.last_departure"])
+                .join_sub(latest(), "latest", "flights.id", "latest.flight_id")
+                .r#where("flights.origin", "AMS")
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT flights.flight_number, latest.last_departure FROM flights JOIN (SELECT flight_id, MAX(departed_at) AS last_departure FROM departures WHERE gate > 10 GROUP BY flight_id) AS latest ON flights.id = latest.flight_id WHERE flights.origin = 'AMS'"
+        );
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT flights.flight_number, latest.last_departure FROM flights JOIN (SELECT flight_id, MAX(departed_at) AS last_departure FROM departures WHERE gate > $1 GROUP BY flight_id) AS latest ON flights.id = latest.flight_id WHERE flights.origin = $2"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::Int(10), Variable::String("AMS".to_string())]
+        );
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .quote_identifiers()
+            .table("flights")
+            .left_join_sub(latest(), "latest", "flights.id", "latest.flight_id")
+            .sql()
+            .unwrap();
+
+        assert!(result.contains("LEFT JOIN (SELECT flight_id, MAX(departed_at) AS \"last_departure\" FROM \"departures\" WHERE gate > 10 GROUP BY flight_id) AS \"latest\" ON"));
+
+        let recent = SubqueryBuilder::new()
+            .table("departures")
+            .select("flight_id")
+            .where_gte("departed_at", Cast("2024-01-01", "date"));
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .join_sub(recent, "recent", "flights.id", "recent.flight_id")
+            .sql()
+            .unwrap();
+
+        assert!(result.contains("JOIN (SELECT flight_id FROM departures WHERE departed_at >= '2024-01-01'::date) AS recent ON"));
+    }
+
+    #[test]
+    fn test_order_by_raw_rejects_unused_bindings() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .order_by_raw("FIELD(id, ?)", vec![1, 2])
+                .limit(3)
+        };
+
+        match query().sql() {
+            Err(EloquentError::UnusedBindings(expression)) => {
+                assert_eq!(expression, "FIELD(id, ?)")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match query().sql_with_bindings() {
+            Err(EloquentError::UnusedBindings(expression)) => {
+                assert_eq!(expression, "FIELD(id, ?)")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_after_cursor_applies_to_every_or_branch() {
+        let query = || {
+            QueryBuilder::new()
+                .table("flights")
+                .r#where("origin", "AMS")
+                .or_where("destination", "AMS")
+                .after_cursor_multi(
+                    vec![
+                        ("departure_time", Box::new("2024-01-01")),
+                        ("id", Box::new(5)),
+                    ],
+                    Order::Asc,
+                )
+                .limit(25)
+        };
+
+        assert_eq!(
+            query().sql().unwrap(),
+            "SELECT * FROM flights WHERE (origin = 'AMS' OR destination = 'AMS') AND (departure_time, id) > ('2024-01-01', 5) ORDER BY departure_time ASC, id ASC LIMIT 25"
+        );
+
+        let (sql, bindings) = query().sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE (origin = ? OR destination = ?) AND (departure_time, id) > (?, ?) ORDER BY departure_time ASC, id ASC LIMIT ?"
+        );
+        assert_eq!(
+            bindings,
+            vec![
+                Variable::String("AMS".to_string()),
+                Variable::String("AMS".to_string()),
+                Variable::String("2024-01-01".to_string()),
+                Variable::Int(5),
+                Variable::UInt(25),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_wraps_unions_and_havings() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("status", "delayed")
+            .union(
+                QueryBuilder::new()
+                    .table("archived_flights")
+                    .r#where("status", "delayed"),
+            )
+            .count();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT COUNT(*) FROM (SELECT * FROM flights WHERE status = 'delayed' UNION (SELECT * FROM archived_flights WHERE status = 'delayed')) AS sub"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_count("id", "flight_count")
+            .group_by("origin")
+            .having_gt("flight_count", 5)
+            .count();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT COUNT(*) FROM (SELECT origin, COUNT(id) AS flight_count FROM flights GROUP BY origin HAVING flight_count > 5) AS sub"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .r#where("status", "delayed")
+            .sharded(2, |shard| format!("_{}", shard))
+            .count();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT COUNT(*) FROM (SELECT * FROM flights_0 WHERE status = 'delayed' UNION ALL SELECT * FROM flights_1 WHERE status = 'delayed') AS sub"
+        );
+    }
+
+    #[test]
+    fn test_subqueries_use_the_outer_dialect_and_quoting() {
+        let subquery = SubqueryBuilder::new()
+            .table("bookings")
+            .select("flight_id")
+            .r#where("status", "confirmed");
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .quote_identifiers()
+            .table("flights")
+            .select("flight_number")
+            .where_in("id", vec![subquery])
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT flight_number FROM \"flights\" WHERE id IN (SELECT flight_id FROM \"bookings\" WHERE status = 'confirmed')"
+        );
+
+        let cte = SubqueryBuilder::new().table("bookings").select("flight_id");
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Mssql)
+            .quote_identifiers()
+            .with("confirmed", cte)
+            .table("confirmed")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "WITH [confirmed] AS (SELECT flight_id FROM [bookings]) SELECT * FROM [confirmed]"
+        );
+    }
+
+    #[test]
+    fn test_select_concat_keeps_arguments_with_commas() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("passengers")
+            .select_concat(
+                vec!["COALESCE(title, '')", "' '", "last_name"],
+                "display_name",
+            )
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT COALESCE(title, '') || ' ' || last_name AS display_name FROM passengers"
+        );
+    }
+
+    #[test]
+    fn test_update_from_keeps_the_join_condition_on_every_row() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .update_from(
+                    "schedules",
+                    vec![("departure_time", "schedules.departure_time")],
+                    ("flights.schedule_id", "schedules.id"),
+                )
+                .r#where("schedules.status", "confirmed")
+                .or_where("schedules.status", "moved")
+                .sql()
+        };
+
+        assert_eq!(
+            query(Dialect::Postgres).unwrap(),
+            "UPDATE flights SET departure_time = schedules.departure_time FROM schedules WHERE (schedules.status = 'confirmed' OR schedules.status = 'moved') AND flights.schedule_id = schedules.id"
+        );
+
+        match query(Dialect::MySql) {
+            Err(EloquentError::UnsupportedByDialect(clause)) => {
+                assert_eq!(clause, "UPDATE FROM")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_distinct_on_rewrite_projects_named_columns() {
+        let rewrite = |query: QueryBuilder| {
+            query
+                .table("flights")
+                .distinct_on_or_group("origin")
+                .order_by_asc("origin")
+                .sql()
+        };
+
+        match rewrite(QueryBuilder::new()) {
+            Err(EloquentError::InvalidDistinctOn(reason)) => assert_eq!(reason, "SELECT *"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match rewrite(
+            QueryBuilder::new()
+                .select("origin")
+                .select_raw("delay_in_min * ?", vec![60]),
+        ) {
+            Err(EloquentError::InvalidDistinctOn(reason)) => {
+                assert_eq!(reason, "the unaliased expression 'delay_in_min * ?'")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        match rewrite(QueryBuilder::new().select("origin").for_update()) {
+            Err(EloquentError::InvalidDistinctOn(reason)) => assert_eq!(reason, "a row lock"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        assert_eq!(
+            rewrite(
+                QueryBuilder::new()
+                    .select("origin")
+                    .select_as("delay_in_min * 60", "delay_in_sec")
+            )
+            .unwrap(),
+            "SELECT origin, delay_in_sec FROM (SELECT origin, delay_in_min * 60 AS delay_in_sec, ROW_NUMBER() OVER (PARTITION BY origin ORDER BY origin ASC) AS row_num FROM flights) AS flights WHERE row_num = 1 ORDER BY origin ASC"
+        );
+    }
+
+    #[test]
+    fn test_cast_arguments_by_dialect() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .from_function("json_each", vec![Box::new(Cast("[1, 2]", "json"))], "j")
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(
+            query(Dialect::Postgres),
+            "SELECT * FROM json_each('[1, 2]'::json) AS j"
+        );
+        assert_eq!(
+            query(Dialect::Sqlite),
+            "SELECT * FROM json_each(CAST('[1, 2]' AS json)) AS j"
+        );
+    }
+
+    #[test]
+    fn test_escapes_backslashes_in_mysql_literals() {
+        let payload = "\\' OR 1=1 -- ";
+
+        let select = QueryBuilder::new()
+            .table("users")
+            .r#where("name", payload)
+            .sql();
+
+        assert_eq!(
+            select.unwrap(),
+            "SELECT * FROM users WHERE name = '\\\\'' OR 1=1 -- '"
+        );
+
+        let insert = QueryBuilder::new()
+            .table("users")
+            .insert("name", "\\'); DROP TABLE users; -- ")
+            .sql();
+
+        assert_eq!(
+            insert.unwrap(),
+            "INSERT INTO users (name) VALUES ('\\\\''); DROP TABLE users; -- ')"
+        );
+
+        let postgres = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("users")
+            .r#where("name", payload)
+            .sql();
+
+        assert_eq!(
+            postgres.unwrap(),
+            "SELECT * FROM users WHERE name = '\\'' OR 1=1 -- '"
+        );
+    }
+
+    #[test]
+    fn test_union_members_inherit_the_outer_format() {
+        let result = QueryBuilder::new()
+            .dialect(Dialect::Postgres)
+            .table("flights")
+            .union(
+                QueryBuilder::new()
+                    .table("archived_flights")
+                    .quote_identifiers(),
+            )
+            .quote_identifiers()
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM \"flights\" UNION (SELECT * FROM \"archived_flights\")"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .union_all(
+                QueryBuilder::new()
+                    .table("archived_flights")
+                    .order_by_desc("departed_at")
+                    .limit(1),
+            )
+            .union_all(QueryBuilder::new().table("charter_flights"))
+            .dialect(Dialect::Sqlite)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights UNION ALL SELECT * FROM (SELECT * FROM archived_flights ORDER BY departed_at DESC LIMIT 1) UNION ALL SELECT * FROM charter_flights"
+        );
+    }
+
+    #[test]
+    fn test_distinct_on_leads_the_postgres_ordering() {
+        let query = |dialect| {
+            QueryBuilder::new()
+                .dialect(dialect)
+                .table("flights")
+                .select(vec!["id", "origin"])
+                .distinct_on_or_group("origin")
+                .order_by_desc("id")
+                .sql()
+                .unwrap()
+        };
+
+        assert_eq!(
+            query(Dialect::Postgres),
+            "SELECT DISTINCT ON (origin) id, origin FROM flights ORDER BY origin, id DESC"
+        );
+        assert_eq!(
+            query(Dialect::Sqlite),
+            "SELECT id, origin FROM (SELECT id, origin, ROW_NUMBER() OVER (PARTITION BY origin ORDER BY id DESC) AS row_num FROM flights) AS flights WHERE row_num = 1 ORDER BY id DESC"
+        );
+    }
+
+    #[test]
+    fn test_wrap_and_filter_moves_statement_clauses_outside() {
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Postgres)
+                .table("flights")
+                .select("id")
+        };
+
+        assert_eq!(
+            query()
+                .tag("team", "ops")
+                .explain()
+                .wrap_and_filter("f", |query| query.r#where("id", 1))
+                .sql()
+                .unwrap(),
+            "EXPLAIN SELECT * FROM (SELECT id FROM flights) AS f WHERE id = 1 /* team=ops */"
+        );
+
+        assert_eq!(
+            query()
+                .for_update()
+                .skip_locked()
+                .wrap_and_filter("f", |query| query.r#where("id", 1))
+                .sql()
+                .unwrap(),
+            "SELECT * FROM (SELECT id FROM flights) AS f WHERE id = 1 FOR UPDATE SKIP LOCKED"
+        );
+    }
+
+    #[test]
+    fn test_mssql_limits_rows_with_offset_fetch() {
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Dialect::Mssql)
+                .table("flights")
+                .r#where("delayed", true)
+        };
+
+        assert_eq!(
+            query().order_by_desc("id").limit(10).sql().unwrap(),
+            "SELECT * FROM flights WHERE delayed = 1 ORDER BY id DESC OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY"
+        );
+        assert_eq!(
+            query().for_page(3, 25).sql().unwrap(),
+            "SELECT * FROM flights WHERE delayed = 1 ORDER BY (SELECT NULL) OFFSET 50 ROWS FETCH NEXT 25 ROWS ONLY"
+        );
+        assert_eq!(
+            query().offset(5).sql().unwrap(),
+            "SELECT * FROM flights WHERE delayed = 1 ORDER BY (SELECT NULL) OFFSET 5 ROWS"
+        );
+
+        let (sql, bindings) = query().default_limit(20).sql_with_bindings().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE delayed = ? ORDER BY (SELECT NULL) OFFSET ? ROWS FETCH NEXT ? ROWS ONLY"
+        );
+        assert_eq!(
+            bindings,
+            vec![Variable::Bool(true), Variable::UInt(0), Variable::UInt(20)]
+        );
+    }
+
+    #[test]
+    fn test_after_cursor_replaces_the_previous_cursor() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .after_cursor("id", 1000, Order::Asc)
+            .after_cursor("id", 2000, Order::Desc)
+            .limit(25)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE id < 2000 ORDER BY id DESC LIMIT 25"
+        );
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .after_cursor_multi(
+                vec![
+                    ("departure_time", Box::new("2024-01-01 10:00:00")),
+                    ("id", Box::new(1000)),
+                ],
+                Order::Asc,
+            )
+            .after_cursor("id", 2000, Order::Asc)
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE id > 2000 ORDER BY id ASC"
+        );
+    }
+
+    #[test]
+    fn test_where_prefix_escapes_wildcards() {
+        let result = QueryBuilder::new()
+            .table("airports")
+            .where_prefix("code", "10%_off!")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM airports WHERE code LIKE '10!%!_off!!%' ESCAPE '!'"
+        );
+
+        let result = QueryBuilder::new()
+            .dialect(Dialect::MySql)
+            .table("airports")
+            .where_prefix("code", "A_")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM airports WHERE code LIKE 'A!_%' ESCAPE '!'"
+        );
+    }
 }
@@ -1,31 +1,23 @@
-use crate::{error::EloquentError, Condition, Logic, Operator, Paginate, ToSql};
+use crate::{error::EloquentError, Condition, Dialect, Logic, Paginate, ToSql};
 
-#[allow(clippy::borrowed_box)]
 pub(crate) fn format<'a>(
     conditions: &'a [Condition],
     closures: &'a [(Logic, Vec<Condition>)],
     paginate: &'a Option<Paginate>,
+    required: &[&'a Condition],
+    dialect: Dialect,
     sql: &mut String,
-    params: &mut Vec<&'a Box<(dyn ToSql + 'static)>>,
+    params: &mut Vec<&'a dyn ToSql>,
 ) -> Result<String, EloquentError> {
-    if conditions.is_empty() && closures.is_empty() && paginate.is_none() {
-        return Ok(sql.to_string());
-    }
+    let mut pagination_str = String::new();
 
     if let Some(paginate) = paginate {
         if let Some(last_id) = &paginate.last_id {
-            sql.push_str(&format!(
-                " WHERE {} > {}",
-                paginate.column,
-                last_id.to_sql()?
-            ));
+            pagination_str.push_str(&format!("{} > ?", paginate.column));
+            params.push(last_id.as_ref());
         }
     }
 
-    if paginate.is_none() {
-        sql.push_str(" WHERE ");
-    }
-
     let mut conditions_str = String::new();
     let mut first_condition = true;
 
@@ -37,12 +29,8 @@ pub(crate) fn format<'a>(
             });
         }
 
-        let condition_sql = condition.format_sql();
-
-        conditions_str.push_str(&condition_sql);
-        if !matches!(condition.operator, Operator::IsNull | Operator::IsNotNull) {
-            params.extend(condition.values.iter());
-        }
+        conditions_str.push_str(&condition.format_sql(dialect)?);
+        params.extend(condition.values.iter().map(|value| value.as_ref()));
 
         first_condition = false;
     }
@@ -64,18 +52,39 @@ pub(crate) fn format<'a>(
                 });
             }
 
-            let condition_sql = condition.format_sql();
-
-            conditions_str.push_str(&condition_sql);
-            if !matches!(condition.operator, Operator::IsNull | Operator::IsNotNull) {
-                params.extend(condition.values.iter());
-            }
+            conditions_str.push_str(&condition.format_sql(dialect)?);
+            params.extend(condition.values.iter().map(|value| value.as_ref()));
         }
         conditions_str.push(')');
         first_condition = false;
     }
 
-    sql.push_str(&conditions_str);
+    let mut required_str = Vec::new();
+
+    for condition in required {
+        required_str.push(condition.format_sql(dialect)?);
+        params.extend(condition.values.iter().map(|value| value.as_ref()));
+    }
+
+    let required_str = required_str.join(" AND ");
+
+    // the pagination and required conditions must hold for every row, so OR branches are kept together
+    let has_or = conditions.iter().skip(1).any(|c| c.logic == Logic::Or)
+        || closures.iter().any(|(logic, _)| *logic == Logic::Or);
+
+    if has_or && !(pagination_str.is_empty() && required_str.is_empty()) {
+        conditions_str = format!("({})", conditions_str);
+    }
+
+    let clauses = [pagination_str, conditions_str, required_str]
+        .into_iter()
+        .filter(|clause| !clause.is_empty())
+        .collect::<Vec<String>>();
+
+    if !clauses.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&clauses.join(" AND "));
+    }
 
     Ok(sql.to_string())
 }
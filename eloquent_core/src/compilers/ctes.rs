@@ -0,0 +1,44 @@
+use crate::{
+    compiler::compile_substatement, error::EloquentError, Cte, Dialect, IdentifierFormat, ToSql,
+};
+
+pub(crate) fn format<'a>(
+    ctes: &'a [Cte],
+    dialect: Dialect,
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    if ctes.is_empty() {
+        return Ok(sql.to_string());
+    }
+
+    sql.push_str("WITH ");
+
+    // SQL Server resolves recursive references without the keyword
+    if dialect != Dialect::Mssql && ctes.iter().any(|cte| cte.recursive) {
+        sql.push_str("RECURSIVE ");
+    }
+
+    let mut definitions = Vec::new();
+
+    for cte in ctes {
+        // the members are compiled without parentheses, which SQLite rejects in a compound select
+        let members = cte
+            .queries
+            .iter()
+            .map(|query| compile_substatement(query, format, params))
+            .collect::<Result<Vec<String>, EloquentError>>()?;
+
+        definitions.push(format!(
+            "{} AS ({})",
+            format.table(&cte.name),
+            members.join(" UNION ALL ")
+        ));
+    }
+
+    sql.push_str(&definitions.join(", "));
+    sql.push(' ');
+
+    Ok(sql.to_string())
+}
@@ -0,0 +1,54 @@
+use crate::{error::EloquentError, Dialect, ExplainOptions};
+
+pub(crate) fn format(
+    explain: &Option<ExplainOptions>,
+    dialect: Dialect,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
+    let explain = match explain {
+        Some(explain) => explain,
+        None => return Ok(sql.to_string()),
+    };
+
+    // SQL Server shows plans through SET SHOWPLAN instead of a statement prefix
+    if dialect == Dialect::Mssql {
+        return Err(EloquentError::UnsupportedByDialect("EXPLAIN".to_string()));
+    }
+
+    let mut options = Vec::new();
+
+    if explain.analyze {
+        options.push("ANALYZE".to_string());
+    }
+
+    if explain.buffers {
+        // buffer usage is only measured while the query runs
+        if !explain.analyze {
+            return Err(EloquentError::InvalidExplainOptions(
+                "BUFFERS requires ANALYZE".to_string(),
+            ));
+        }
+
+        options.push("BUFFERS".to_string());
+    }
+
+    if let Some(format) = explain.format {
+        options.push(format!("FORMAT {}", format));
+    }
+
+    if options.is_empty() {
+        sql.push_str("EXPLAIN ");
+
+        return Ok(sql.to_string());
+    }
+
+    if dialect != Dialect::Postgres {
+        return Err(EloquentError::UnsupportedByDialect(
+            "EXPLAIN options".to_string(),
+        ));
+    }
+
+    sql.push_str(&format!("EXPLAIN ({}) ", options.join(", ")));
+
+    Ok(sql.to_string())
+}
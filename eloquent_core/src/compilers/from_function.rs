@@ -0,0 +1,33 @@
+use crate::{error::EloquentError, Dialect, FromFunction, ToSql};
+
+pub(crate) fn format<'a>(
+    from_function: &'a FromFunction,
+    dialect: Dialect,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    if from_function.with_ordinality && dialect != Dialect::Postgres {
+        return Err(EloquentError::UnsupportedByDialect(
+            "WITH ORDINALITY".to_string(),
+        ));
+    }
+
+    params.extend(from_function.args.iter().map(|arg| arg.as_ref()));
+
+    let mut sql = format!(
+        "{}({})",
+        from_function.name,
+        vec!["?"; from_function.args.len()].join(", ")
+    );
+
+    if from_function.with_ordinality {
+        sql.push_str(" WITH ORDINALITY");
+    }
+
+    sql.push_str(&format!(" AS {}", from_function.alias));
+
+    if !from_function.columns.is_empty() {
+        sql.push_str(&format!("({})", from_function.columns.join(", ")));
+    }
+
+    Ok(sql)
+}
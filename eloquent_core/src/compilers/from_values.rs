@@ -0,0 +1,50 @@
+use crate::{Dialect, FromValues, ToSql};
+
+pub(crate) fn format<'a>(
+    from_values: &'a FromValues,
+    dialect: Dialect,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> String {
+    let rows = from_values.rows.iter().map(|row| {
+        params.extend(row.iter().map(|value| value.as_ref()));
+        vec!["?"; row.len()].join(", ")
+    });
+
+    match dialect {
+        // SQLite cannot name the columns of a derived table, so the rows are selected with aliases
+        Dialect::Sqlite => {
+            let selects = rows
+                .enumerate()
+                .map(|(i, row)| match i {
+                    0 => format!(
+                        "SELECT {}",
+                        row.split(", ")
+                            .zip(&from_values.columns)
+                            .map(|(value, column)| format!("{} AS {}", value, column))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    _ => format!("SELECT {}", row),
+                })
+                .collect::<Vec<String>>()
+                .join(" UNION ALL ");
+
+            format!("({}) AS {}", selects, from_values.alias)
+        }
+        _ => {
+            let constructor = match dialect {
+                Dialect::MySql => "ROW",
+                _ => "",
+            };
+
+            format!(
+                "(VALUES {}) AS {}({})",
+                rows.map(|row| format!("{}({})", constructor, row))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                from_values.alias,
+                from_values.columns.join(", ")
+            )
+        }
+    }
+}
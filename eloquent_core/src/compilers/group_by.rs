@@ -1,8 +1,25 @@
-pub(crate) fn format(group_by: &[String], sql: &mut String) -> String {
+use crate::{error::EloquentError, Dialect};
+
+pub(crate) fn format(
+    group_by: &[String],
+    rollup: bool,
+    dialect: Dialect,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
     if !group_by.is_empty() {
         sql.push_str(" GROUP BY ");
-        sql.push_str(&group_by.join(", "));
+
+        match (rollup, dialect) {
+            (false, _) => sql.push_str(&group_by.join(", ")),
+            (true, Dialect::MySql) => sql.push_str(&format!("{} WITH ROLLUP", group_by.join(", "))),
+            (true, Dialect::Postgres | Dialect::Mssql | Dialect::AnsiStandard) => {
+                sql.push_str(&format!("ROLLUP({})", group_by.join(", ")))
+            }
+            (true, Dialect::Sqlite) => {
+                return Err(EloquentError::UnsupportedByDialect("ROLLUP".to_string()))
+            }
+        }
     }
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
@@ -1,25 +1,35 @@
-use crate::{error::EloquentError, Having};
+use crate::{error::EloquentError, Dialect, Having, Logic, ToSql};
 
-pub(crate) fn format(havings: &[Having], sql: &mut String) -> Result<String, EloquentError> {
+pub(crate) fn format<'a>(
+    havings: &'a [Having],
+    dialect: Dialect,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
     if !havings.is_empty() {
         sql.push_str(" HAVING ");
 
-        let havings = &havings;
+        for (i, clause) in havings.iter().enumerate() {
+            // a clause is joined to the previous ones by the logic of its first condition
+            if i > 0 {
+                sql.push_str(match clause.conditions.first().map(|c| &c.logic) {
+                    Some(Logic::Or) => " OR ",
+                    _ => " AND ",
+                });
+            }
 
-        sql.push_str(
-            &havings
-                .iter()
-                .map(|clause| {
-                    clause
-                        .conditions
-                        .iter()
-                        .map(|condition| condition.format_sql())
-                        .collect::<Vec<String>>()
-                        .join(" AND ")
-                })
-                .collect::<Vec<String>>()
-                .join(", "),
-        );
+            sql.push_str(
+                &clause
+                    .conditions
+                    .iter()
+                    .map(|condition| {
+                        params.extend(condition.values.iter().map(|value| value.as_ref()));
+                        condition.format_sql(dialect)
+                    })
+                    .collect::<Result<Vec<String>, EloquentError>>()?
+                    .join(" AND "),
+            );
+        }
     }
 
     Ok(sql.to_string())
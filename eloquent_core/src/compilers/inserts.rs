@@ -1,20 +1,21 @@
-use crate::{Insert, ToSql};
+use crate::{error::EloquentError, Assignment, Dialect, IdentifierFormat, Insert, ToSql, Upsert};
 
-#[allow(clippy::borrowed_box)]
 pub(crate) fn format<'a>(
     table: &str,
     inserts: &'a [Insert],
+    insert_rows: &'a [Vec<Insert>],
+    format: IdentifierFormat,
     sql: &mut String,
-    params: &mut Vec<&'a Box<(dyn ToSql + 'static)>>,
-) -> String {
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
     sql.push_str("INSERT INTO ");
-    sql.push_str(table);
+    sql.push_str(&format.table(table));
     sql.push_str(" (");
 
     sql.push_str(
         &inserts
             .iter()
-            .map(|insert| insert.column.clone())
+            .map(|insert| format.identifier(&insert.column))
             .collect::<Vec<String>>()
             .join(", "),
     );
@@ -25,7 +26,7 @@ pub(crate) fn format<'a>(
         &inserts
             .iter()
             .map(|insert| {
-                params.push(&insert.value);
+                params.push(insert.value.as_ref());
                 "?".to_string()
             })
             .collect::<Vec<String>>()
@@ -34,5 +35,195 @@ pub(crate) fn format<'a>(
 
     sql.push(')');
 
-    sql.to_string()
+    for row in insert_rows {
+        if let Some(extra) = row
+            .iter()
+            .find(|value| !inserts.iter().any(|insert| insert.column == value.column))
+        {
+            return Err(EloquentError::InconsistentInsertColumns(
+                extra.column.clone(),
+            ));
+        }
+
+        if let Some(duplicate) = row.iter().enumerate().find_map(|(i, value)| {
+            row[..i]
+                .iter()
+                .any(|previous| previous.column == value.column)
+                .then_some(value)
+        }) {
+            return Err(EloquentError::InconsistentInsertColumns(
+                duplicate.column.clone(),
+            ));
+        }
+
+        sql.push_str(", (");
+
+        // values are aligned with the columns of the first row
+        for (i, insert) in inserts.iter().enumerate() {
+            let value = row
+                .iter()
+                .find(|value| value.column == insert.column)
+                .ok_or_else(|| EloquentError::InconsistentInsertColumns(insert.column.clone()))?;
+
+            if i > 0 {
+                sql.push_str(", ");
+            }
+
+            sql.push('?');
+            params.push(value.value.as_ref());
+        }
+
+        sql.push(')');
+    }
+
+    Ok(sql.to_string())
+}
+
+pub(crate) fn format_on_duplicate_key_update<'a>(
+    assignments: &'a [Assignment],
+    conflict_target: &Option<Vec<String>>,
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    match conflict_target {
+        Some(columns) if columns.is_empty() => return Err(EloquentError::MissingConflictColumns),
+        Some(columns) => {
+            sql.push_str(&format!(
+                " ON CONFLICT ({})",
+                columns
+                    .iter()
+                    .map(|column| format.identifier(column))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+
+            // a conflict target without updates skips the conflicting rows
+            if assignments.is_empty() {
+                sql.push_str(" DO NOTHING");
+
+                return Ok(sql.to_string());
+            }
+
+            sql.push_str(" DO UPDATE SET ");
+        }
+        None if assignments.is_empty() => return Ok(sql.to_string()),
+        None => sql.push_str(" ON DUPLICATE KEY UPDATE "),
+    }
+
+    sql.push_str(
+        &assignments
+            .iter()
+            .map(|assignment| {
+                params.extend(assignment.values.iter().map(|value| value.as_ref()));
+                format!(
+                    "{} = {}",
+                    format.identifier(&assignment.column),
+                    assignment.expression
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", "),
+    );
+
+    Ok(sql.to_string())
+}
+
+pub(crate) fn format_returning(
+    returning: &[String],
+    inserted_flag: &Option<String>,
+    multiple_statements: bool,
+    format: IdentifierFormat,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
+    let mut columns = returning
+        .iter()
+        .map(|column| format.identifier(column))
+        .collect::<Vec<String>>();
+
+    if let Some(alias) = inserted_flag {
+        // xmax is only set on the row version written by an update
+        columns.push(format.alias("(xmax = 0)", alias));
+    }
+
+    if columns.is_empty() {
+        return Ok(sql.to_string());
+    }
+
+    match (format.dialect, returning) {
+        (Dialect::Postgres | Dialect::Sqlite, _) => {
+            sql.push_str(" RETURNING ");
+            sql.push_str(&columns.join(", "));
+        }
+        // only the generated key of the last insert can be read back
+        (Dialect::MySql, [column]) if multiple_statements => {
+            sql.push_str("; SELECT ");
+            sql.push_str(&format.alias("LAST_INSERT_ID()", column));
+        }
+        _ => return Err(EloquentError::UnsupportedByDialect("RETURNING".to_string())),
+    }
+
+    Ok(sql.to_string())
+}
+
+pub(crate) fn format_upsert(
+    upsert: &Option<Upsert>,
+    dialect: Dialect,
+    format: IdentifierFormat,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
+    let Some(upsert) = upsert else {
+        return Ok(sql.to_string());
+    };
+
+    if upsert.conflict_columns.is_empty() {
+        return Err(EloquentError::MissingConflictColumns);
+    }
+
+    let identifiers = |columns: &[String]| {
+        columns
+            .iter()
+            .map(|column| format.identifier(column))
+            .collect::<Vec<String>>()
+    };
+
+    let conflict_columns = identifiers(&upsert.conflict_columns);
+    let update_columns = identifiers(&upsert.update_columns);
+
+    match dialect {
+        // MySQL has no DO NOTHING, so the row is kept with an assignment that changes nothing
+        Dialect::MySql if update_columns.is_empty() => sql.push_str(&format!(
+            " ON DUPLICATE KEY UPDATE {} = {}",
+            conflict_columns[0], conflict_columns[0]
+        )),
+        // MySQL resolves the conflict from the unique keys of the table
+        Dialect::MySql => sql.push_str(&format!(
+            " ON DUPLICATE KEY UPDATE {}",
+            update_columns
+                .iter()
+                .map(|column| format!("{} = VALUES({})", column, column))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+        Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard
+            if update_columns.is_empty() =>
+        {
+            sql.push_str(&format!(
+                " ON CONFLICT ({}) DO NOTHING",
+                conflict_columns.join(", ")
+            ))
+        }
+        Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard => sql.push_str(&format!(
+            " ON CONFLICT ({}) DO UPDATE SET {}",
+            conflict_columns.join(", "),
+            update_columns
+                .iter()
+                .map(|column| format!("{} = EXCLUDED.{}", column, column))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+        Dialect::Mssql => return Err(EloquentError::UnsupportedByDialect("UPSERT".to_string())),
+    }
+
+    Ok(sql.to_string())
 }
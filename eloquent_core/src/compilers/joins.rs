@@ -1,18 +1,78 @@
-use crate::Join;
+use crate::{error::EloquentError, Condition, IdentifierFormat, Join, JoinType, Logic, ToSql};
 
-pub(crate) fn format(joins: &[Join], sql: &mut String) -> String {
+pub(crate) fn format<'a>(
+    joins: &'a [Join],
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
     for join in joins {
         sql.push(' ');
 
         sql.push_str(&join.join_type.to_string());
 
         sql.push(' ');
-        sql.push_str(&join.table);
+
+        match &join.subquery {
+            Some(subquery) => {
+                // the subquery is compiled in place of its placeholder, with its own parentheses
+                params.push(subquery);
+
+                sql.push_str(&format.alias("?", &join.table));
+            }
+            None => sql.push_str(&format.table(&join.table)),
+        }
+
+        if join.join_type == JoinType::Cross {
+            continue;
+        }
+
         sql.push_str(" ON ");
-        sql.push_str(&join.left_hand);
-        sql.push_str(" = ");
-        sql.push_str(&join.right_hand);
+
+        if join.conditions.is_empty() && join.closures.is_empty() {
+            sql.push_str(&join.left_hand);
+            sql.push_str(" = ");
+            sql.push_str(&join.right_hand);
+
+            continue;
+        }
+
+        format_conditions(&join.conditions, format, sql, params)?;
+
+        for (i, (logic, closure)) in join.closures.iter().enumerate() {
+            if i > 0 || !join.conditions.is_empty() {
+                sql.push_str(match logic {
+                    Logic::And => " AND ",
+                    Logic::Or => " OR ",
+                });
+            }
+
+            sql.push('(');
+            format_conditions(closure, format, sql, params)?;
+            sql.push(')');
+        }
+    }
+
+    Ok(sql.to_string())
+}
+
+fn format_conditions<'a>(
+    conditions: &'a [Condition],
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<(), EloquentError> {
+    for (i, condition) in conditions.iter().enumerate() {
+        if i > 0 {
+            sql.push_str(match condition.logic {
+                Logic::And => " AND ",
+                Logic::Or => " OR ",
+            });
+        }
+
+        sql.push_str(&condition.format_sql(format.dialect)?);
+        params.extend(condition.values.iter().map(|value| value.as_ref()));
     }
 
-    sql.to_string()
+    Ok(())
 }
@@ -1,10 +1,41 @@
-use crate::Paginate;
+use crate::{Dialect, Paginate, ToSql};
 
-pub(crate) fn format(limit: &Option<u64>, paginate: &Option<Paginate>, sql: &mut String) -> String {
-    if let Some(paginate) = paginate {
-        sql.push_str(&format!(" LIMIT {}", paginate.per_page));
-    } else if let Some(limit) = &limit {
-        sql.push_str(&format!(" LIMIT {}", limit));
+pub(crate) fn format<'a>(
+    limit: &'a Option<u64>,
+    offset: &'a Option<u64>,
+    paginate: &'a Option<Paginate>,
+    ordered: bool,
+    dialect: Dialect,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> String {
+    let limit = match paginate {
+        Some(paginate) => Some(&paginate.per_page),
+        None => limit.as_ref(),
+    };
+
+    // SQL Server limits rows with OFFSET .. FETCH, which requires an ORDER BY and always starts with the offset
+    if dialect == Dialect::Mssql {
+        if limit.is_some() || offset.is_some() {
+            if !ordered {
+                sql.push_str(" ORDER BY (SELECT NULL)");
+            }
+
+            sql.push_str(" OFFSET ? ROWS");
+            params.push(offset.as_ref().unwrap_or(&0));
+
+            if let Some(limit) = limit {
+                sql.push_str(" FETCH NEXT ? ROWS ONLY");
+                params.push(limit);
+            }
+        }
+
+        return sql.to_string();
+    }
+
+    if let Some(limit) = limit {
+        sql.push_str(" LIMIT ?");
+        params.push(limit);
     }
 
     sql.to_string()
@@ -0,0 +1,41 @@
+use crate::{error::EloquentError, Dialect, Lock, LockMode, LockWait};
+
+pub(crate) fn format(
+    lock: &Option<Lock>,
+    wait: &Option<LockWait>,
+    dialect: Dialect,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
+    if let Some(lock) = lock {
+        // SQLite locks the whole database and SQL Server uses table hints instead
+        if matches!(dialect, Dialect::Sqlite | Dialect::Mssql) {
+            return Err(EloquentError::UnsupportedByDialect(lock.mode.to_string()));
+        }
+
+        // the legacy MySQL clause is understood by every version but accepts no modifiers
+        if dialect == Dialect::MySql
+            && matches!(lock.mode, LockMode::Share)
+            && lock.tables.is_empty()
+            && wait.is_none()
+        {
+            sql.push_str(" LOCK IN SHARE MODE");
+
+            return Ok(sql.to_string());
+        }
+
+        sql.push(' ');
+        sql.push_str(&lock.mode.to_string());
+
+        if !lock.tables.is_empty() {
+            sql.push_str(" OF ");
+            sql.push_str(&lock.tables.join(", "));
+        }
+
+        if let Some(wait) = wait {
+            sql.push(' ');
+            sql.push_str(&wait.to_string());
+        }
+    }
+
+    Ok(sql.to_string())
+}
@@ -0,0 +1,73 @@
+use crate::{error::EloquentError, Dialect, IdentifierFormat, Merge};
+
+pub(crate) fn format(
+    table: &str,
+    merge: &Merge,
+    format: IdentifierFormat,
+    sql: &mut String,
+) -> Result<String, EloquentError> {
+    let source = merge
+        .source
+        .as_ref()
+        .ok_or(EloquentError::IncompleteMerge("USING".to_string()))?;
+    let (left_hand, right_hand) = merge
+        .on
+        .as_ref()
+        .ok_or(EloquentError::IncompleteMerge("ON".to_string()))?;
+
+    if merge.matched_updates.is_empty() && merge.not_matched_insert.is_none() {
+        return Err(EloquentError::IncompleteMerge("WHEN".to_string()));
+    }
+
+    if let Some((columns, values)) = &merge.not_matched_insert {
+        if columns.len() != values.len() {
+            return Err(EloquentError::MismatchedMergeInsert(format!(
+                "{} columns and {} values",
+                columns.len(),
+                values.len()
+            )));
+        }
+    }
+
+    sql.push_str("MERGE INTO ");
+    sql.push_str(&format.table(table));
+    sql.push_str(" USING ");
+    sql.push_str(&format.table(source));
+    sql.push_str(" ON ");
+    sql.push_str(left_hand);
+    sql.push_str(" = ");
+    sql.push_str(right_hand);
+
+    if !merge.matched_updates.is_empty() {
+        sql.push_str(" WHEN MATCHED THEN UPDATE SET ");
+        sql.push_str(
+            &merge
+                .matched_updates
+                .iter()
+                .map(|(column, value)| format!("{} = {}", format.identifier(column), value))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+    }
+
+    if let Some((columns, values)) = &merge.not_matched_insert {
+        sql.push_str(" WHEN NOT MATCHED THEN INSERT (");
+        sql.push_str(
+            &columns
+                .iter()
+                .map(|column| format.identifier(column))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+        sql.push_str(") VALUES (");
+        sql.push_str(&values.join(", "));
+        sql.push(')');
+    }
+
+    // SQL Server requires a MERGE statement to be terminated
+    if format.dialect == Dialect::Mssql {
+        sql.push(';');
+    }
+
+    Ok(sql.to_string())
+}
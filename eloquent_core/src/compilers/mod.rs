@@ -1,11 +1,19 @@
 pub mod conditions;
+pub mod ctes;
 pub mod delete;
+pub mod explain;
+pub mod from_function;
+pub mod from_values;
 pub mod group_by;
 pub mod havings;
 pub mod inserts;
 pub mod joins;
 pub mod limit;
+pub mod lock;
+pub mod merge;
 pub mod offset;
 pub mod order_by;
 pub mod selects;
+pub mod tags;
+pub mod unions;
 pub mod updates;
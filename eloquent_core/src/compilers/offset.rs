@@ -1,6 +1,19 @@
-pub(crate) fn format(offset: &Option<u64>, sql: &mut String) -> String {
-    if let Some(offset) = &offset {
-        sql.push_str(&format!(" OFFSET {}", offset));
+use crate::{Dialect, ToSql};
+
+pub(crate) fn format<'a>(
+    offset: &'a Option<u64>,
+    dialect: Dialect,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> String {
+    // SQL Server renders the offset together with the limit
+    if dialect == Dialect::Mssql {
+        return sql.to_string();
+    }
+
+    if let Some(offset) = offset {
+        sql.push_str(" OFFSET ?");
+        params.push(offset);
     }
 
     sql.to_string()
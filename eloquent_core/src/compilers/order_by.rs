@@ -1,18 +1,88 @@
-use crate::OrderColumn;
+use crate::{error::EloquentError, Dialect, Nulls, OrderColumn, OrderKind, ToSql};
 
-pub(crate) fn format(order_by: &[OrderColumn], sql: &mut String) -> String {
+pub(crate) fn format<'a>(
+    order_by: &'a [OrderColumn],
+    dialect: Dialect,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
+    format_renamed(order_by, dialect, |column| column.to_string(), sql, params)
+}
+
+/// Format the order by clause with the column names rewritten, raw expressions are kept as is.
+pub(crate) fn format_renamed<'a, F>(
+    order_by: &'a [OrderColumn],
+    dialect: Dialect,
+    rename: F,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError>
+where
+    F: Fn(&str) -> String,
+{
     if !order_by.is_empty() {
         sql.push_str(" ORDER BY ");
-        order_by.iter().for_each(|order| {
-            sql.push_str(&order.column);
-            sql.push(' ');
-            sql.push_str(&order.order.to_string());
+        sql.push_str(&format_columns(order_by, dialect, rename, params)?);
+    }
 
-            if order != order_by.last().unwrap() {
-                sql.push_str(", ");
+    Ok(sql.to_string())
+}
+
+/// Format the ordering terms without the `ORDER BY` keyword, e.g. for a window definition.
+pub(crate) fn format_columns<'a, F>(
+    order_by: &'a [OrderColumn],
+    dialect: Dialect,
+    rename: F,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError>
+where
+    F: Fn(&str) -> String,
+{
+    let columns = order_by
+        .iter()
+        .map(|order| {
+            if order.values.len() > order.column.matches('?').count() {
+                return Err(EloquentError::UnusedBindings(order.column.clone()));
             }
-        });
-    }
 
-    sql.to_string()
+            params.extend(order.values.iter().map(|value| value.as_ref()));
+
+            Ok(match order.kind {
+                OrderKind::Column => format_column(&rename(&order.column), order, dialect),
+                OrderKind::Raw => order.column.clone(),
+                OrderKind::Random => match dialect {
+                    Dialect::MySql => "RAND()".to_string(),
+                    Dialect::Mssql => "NEWID()".to_string(),
+                    Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard => {
+                        "RANDOM()".to_string()
+                    }
+                },
+            })
+        })
+        .collect::<Result<Vec<String>, EloquentError>>()?;
+
+    Ok(columns.join(", "))
+}
+
+fn format_column(name: &str, order: &OrderColumn, dialect: Dialect) -> String {
+    let column = format!("{} {}", name, order.order);
+
+    match (order.nulls, dialect) {
+        (None, _) => column,
+        (Some(nulls), Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard) => {
+            format!("{} {}", column, nulls)
+        }
+        // MySQL and SQL Server have no NULLS clause, so null values are sorted by a leading key
+        (Some(nulls), _) => {
+            let (null, not_null) = match nulls {
+                Nulls::First => (0, 1),
+                Nulls::Last => (1, 0),
+            };
+
+            format!(
+                "CASE WHEN {} IS NULL THEN {} ELSE {} END, {}",
+                name, null, not_null, column
+            )
+        }
+    }
 }
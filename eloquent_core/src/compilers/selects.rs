@@ -1,22 +1,75 @@
-use crate::Select;
+use crate::{error::EloquentError, Dialect, Function, IdentifierFormat, Select, ToSql};
 
-pub(crate) fn format(table: &str, selects: &[Select], sql: &mut String) -> String {
+pub(crate) fn format<'a>(
+    table: &str,
+    selects: &'a [Select],
+    distinct_on: &[String],
+    row_number: Option<&str>,
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> Result<String, EloquentError> {
     sql.push_str("SELECT ");
 
+    if !distinct_on.is_empty() {
+        sql.push_str(&format!("DISTINCT ON ({}) ", distinct_on.join(", ")));
+    }
+
     if selects.is_empty() {
         sql.push('*');
     } else {
+        for select in selects {
+            if select.values.len() > select.column.matches('?').count() {
+                return Err(EloquentError::UnusedBindings(select.column.clone()));
+            }
+
+            // only the standard allows DISTINCT inside a windowed aggregate
+            if select.window.is_some()
+                && matches!(select.function, Some(Function::CountDistinct))
+                && format.dialect != Dialect::AnsiStandard
+            {
+                return Err(EloquentError::UnsupportedByDialect(
+                    "DISTINCT window aggregate".to_string(),
+                ));
+            }
+
+            // SQLite only takes DISTINCT without a separator and SQL Server not at all
+            if matches!(
+                select.function,
+                Some(Function::GroupConcat { distinct: true, .. })
+            ) && matches!(format.dialect, Dialect::Sqlite | Dialect::Mssql)
+            {
+                return Err(EloquentError::UnsupportedByDialect(
+                    "DISTINCT GROUP_CONCAT".to_string(),
+                ));
+            }
+
+            params.extend(select.values.iter().map(|value| value.as_ref()));
+
+            if let Some(Function::GroupConcat { separator, .. }) = &select.function {
+                match format.dialect {
+                    Dialect::MySql => params.push(separator),
+                    _ => params.push(&separator.0),
+                }
+            }
+        }
+
         sql.push_str(
             &selects
                 .iter()
-                .map(|s| s.format_column_name())
+                .map(|s| s.format_column_name(format))
                 .collect::<Vec<String>>()
                 .join(", "),
         );
     }
 
+    if let Some(row_number) = row_number {
+        sql.push_str(", ");
+        sql.push_str(row_number);
+    }
+
     sql.push_str(" FROM ");
     sql.push_str(table);
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
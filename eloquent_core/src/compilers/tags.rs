@@ -0,0 +1,20 @@
+pub(crate) fn format(tags: &[(String, String)], sql: &mut String) -> String {
+    if !tags.is_empty() {
+        sql.push_str(" /* ");
+        sql.push_str(
+            &tags
+                .iter()
+                .map(|(key, value)| format!("{}={}", sanitize(key), sanitize(value)))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+        sql.push_str(" */");
+    }
+
+    sql.to_string()
+}
+
+/// Break up comment delimiters so a tag can never close or nest the comment.
+fn sanitize(tag: &str) -> String {
+    tag.trim().replace("*/", "* /").replace("/*", "/ *")
+}
@@ -0,0 +1,27 @@
+use crate::{Dialect, ToSql, Union};
+
+pub(crate) fn format<'a>(
+    unions: &'a [Union],
+    dialect: Dialect,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> String {
+    for union in unions {
+        sql.push_str(match union.all {
+            true => " UNION ALL ",
+            false => " UNION ",
+        });
+
+        // SQLite does not accept parenthesized members in a compound select,
+        // so members with their own ordering or limit are selected from instead
+        sql.push_str(match dialect {
+            Dialect::Sqlite if union.query.limits_rows() => "SELECT * FROM (?)",
+            Dialect::Sqlite => "?",
+            _ => "(?)",
+        });
+
+        params.push(&union.query);
+    }
+
+    sql.to_string()
+}
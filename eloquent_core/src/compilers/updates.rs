@@ -1,22 +1,47 @@
-use crate::{ToSql, Update};
+use crate::{IdentifierFormat, ToSql, Update};
 
-#[allow(clippy::borrowed_box)]
 pub(crate) fn format<'a>(
     table: &str,
     updates: &'a [Update],
+    update_from: Option<&str>,
+    format: IdentifierFormat,
     sql: &mut String,
-    params: &mut Vec<&'a Box<(dyn ToSql + 'static)>>,
+    params: &mut Vec<&'a dyn ToSql>,
 ) -> String {
     sql.push_str("UPDATE ");
-    sql.push_str(table);
-    sql.push_str(" SET ");
+    sql.push_str(&format.table(table));
+    sql.push(' ');
+
+    format_set(updates, format, sql, params);
+
+    if let Some(source_table) = update_from {
+        sql.push_str(" FROM ");
+        sql.push_str(&format.table(source_table));
+    }
+
+    sql.to_string()
+}
+
+pub(crate) fn format_set<'a>(
+    updates: &'a [Update],
+    format: IdentifierFormat,
+    sql: &mut String,
+    params: &mut Vec<&'a dyn ToSql>,
+) -> String {
+    sql.push_str("SET ");
 
     sql.push_str(
         &updates
             .iter()
             .map(|update| {
-                params.push(&update.value);
-                format!("{} = ?", update.column)
+                params.push(update.value.as_ref());
+
+                let column = format.identifier(&update.column);
+
+                match update.operator {
+                    Some(operator) => format!("{} = {} {} ?", column, column, operator),
+                    None => format!("{} = ?", column),
+                }
             })
             .collect::<Vec<String>>()
             .join(", "),
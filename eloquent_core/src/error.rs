@@ -8,10 +8,37 @@ pub enum EloquentError {
     OrderByWithNonSelectedOrAggregateFunction(String),
     MultipleCrudActions,
     MissingPlaceholders,
+    InliningDisabled,
+    UnusedBindings(String),
     CannotApplyClauseOnInsert(String),
     CannotApplyClauseOnUpdate(String),
     CannotApplyClauseOnDelete(String),
+    CannotApplyClauseOnMerge(String),
     CannotUseOffsetLimitWithPagination(String),
+    LockOnUnknownTable(String),
+    InvalidIdentifier(String),
+    UnsupportedByDialect(String),
+    IncompleteMerge(String),
+    MismatchedMergeInsert(String),
+    AmbiguousResultColumn(String),
+    DuplicatedInsertColumn(String),
+    InconsistentInsertColumns(String),
+    MissingConflictColumns,
+    MissingJoinConditions(String),
+    ConflictingUpserts(String),
+    UnionWithNonSelect,
+    TableWithFromSubquery(String),
+    MultipleFromSources(String),
+    InvalidLock(String),
+    InvalidDistinctOn(String),
+    InvalidExplainOptions(String),
+    InvalidFromValues(String),
+    InvalidShards(String),
+    RollupWithoutGroupBy,
+    UngroupableExpression(String),
+    EmptyCase(String),
+    EmptyInsert,
+    NonFiniteFloat(String),
 }
 
 impl std::error::Error for EloquentError {}
@@ -45,6 +72,12 @@ impl std::fmt::Display for EloquentError {
             }
             EloquentError::MultipleCrudActions => write!(f, "Multiple CRUD actions"),
             EloquentError::MissingPlaceholders => write!(f, "Missing placeholders"),
+            EloquentError::InliningDisabled => {
+                write!(f, "Inlining values is disabled by strict bindings")
+            }
+            EloquentError::UnusedBindings(fragment) => {
+                write!(f, "More bindings than placeholders in '{}'", fragment)
+            }
             EloquentError::CannotApplyClauseOnInsert(clause) => {
                 write!(f, "Cannot apply clause '{}' on INSERT", clause)
             }
@@ -54,9 +87,82 @@ impl std::fmt::Display for EloquentError {
             EloquentError::CannotApplyClauseOnDelete(clause) => {
                 write!(f, "Cannot apply clause '{}' on DELETE", clause)
             }
+            EloquentError::CannotApplyClauseOnMerge(clause) => {
+                write!(f, "Cannot apply clause '{}' on MERGE", clause)
+            }
             EloquentError::CannotUseOffsetLimitWithPagination(clause) => {
                 write!(f, "Cannot use '{}' with PAGINATION", clause)
             }
+            EloquentError::LockOnUnknownTable(table) => {
+                write!(
+                    f,
+                    "Cannot lock table '{}' that is not part of the query",
+                    table
+                )
+            }
+            EloquentError::InvalidIdentifier(identifier) => {
+                write!(f, "Invalid identifier '{}'", identifier)
+            }
+            EloquentError::UnsupportedByDialect(clause) => {
+                write!(f, "'{}' is not supported by the selected dialect", clause)
+            }
+            EloquentError::IncompleteMerge(clause) => {
+                write!(f, "MERGE without '{}' clause", clause)
+            }
+            EloquentError::MismatchedMergeInsert(counts) => {
+                write!(f, "MERGE insert with {}", counts)
+            }
+            EloquentError::AmbiguousResultColumn(column) => {
+                write!(f, "Ambiguous result column '{}'", column)
+            }
+            EloquentError::DuplicatedInsertColumn(column) => {
+                write!(f, "Duplicated insert column '{}'", column)
+            }
+            EloquentError::InconsistentInsertColumns(column) => {
+                write!(f, "Insert rows have inconsistent column '{}'", column)
+            }
+            EloquentError::MissingConflictColumns => write!(f, "Upsert without conflict columns"),
+            EloquentError::MissingJoinConditions(table) => {
+                write!(f, "Join on '{}' without conditions", table)
+            }
+            EloquentError::ConflictingUpserts(clauses) => {
+                write!(f, "Conflicting upserts '{}'", clauses)
+            }
+            EloquentError::UnionWithNonSelect => write!(f, "UNION with a non-SELECT query"),
+            EloquentError::RollupWithoutGroupBy => write!(f, "ROLLUP without a GROUP BY"),
+            EloquentError::UngroupableExpression(expression) => {
+                write!(
+                    f,
+                    "Cannot group by the expression '{}' with bindings",
+                    expression
+                )
+            }
+            EloquentError::NonFiniteFloat(value) => {
+                write!(f, "Cannot render non-finite float '{}'", value)
+            }
+            EloquentError::EmptyCase(alias) => write!(f, "CASE '{}' without WHEN branches", alias),
+            EloquentError::EmptyInsert => write!(f, "INSERT without rows"),
+            EloquentError::InvalidExplainOptions(reason) => {
+                write!(f, "Invalid EXPLAIN options: {}", reason)
+            }
+            EloquentError::InvalidFromValues(reason) => {
+                write!(f, "Invalid VALUES rows: {}", reason)
+            }
+            EloquentError::InvalidShards(reason) => {
+                write!(f, "Invalid shards: {}", reason)
+            }
+            EloquentError::InvalidDistinctOn(reason) => {
+                write!(f, "Cannot rewrite DISTINCT ON with {}", reason)
+            }
+            EloquentError::InvalidLock(clause) => {
+                write!(f, "Cannot apply '{}' outside a locking SELECT", clause)
+            }
+            EloquentError::MultipleFromSources(sources) => {
+                write!(f, "Multiple FROM sources '{}'", sources)
+            }
+            EloquentError::TableWithFromSubquery(table) => {
+                write!(f, "Cannot select from table '{}' and a subquery", table)
+            }
         }
     }
 }
@@ -0,0 +1,103 @@
+use crate::{Column, Comparison, Condition, JoinClause, Logic, Operator, ToSql};
+
+impl JoinClause {
+    /// Create a new `ON` clause without any conditions.
+    pub fn new() -> Self {
+        Self {
+            conditions: Vec::new(),
+            closures: Vec::new(),
+        }
+    }
+
+    /// Add a condition comparing two columns.
+    pub fn on_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_column(left_hand, operator.into(), right_hand, Logic::And)
+    }
+
+    /// Add an OR condition comparing two columns.
+    pub fn or_on_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_column(left_hand, operator.into(), right_hand, Logic::Or)
+    }
+
+    /// Add a condition comparing a column to a value.
+    pub fn on_value(self, column: &str, operator: Comparison, value: impl ToSql + 'static) -> Self {
+        self.add_condition(column, operator.into(), Logic::And, Box::new(value))
+    }
+
+    /// Add an OR condition comparing a column to a value.
+    pub fn or_on_value(
+        self,
+        column: &str,
+        operator: Comparison,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(column, operator.into(), Logic::Or, Box::new(value))
+    }
+
+    /// Add a group of conditions built by the closure, wrapped in parentheses.
+    pub fn on_closure<F>(self, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_closure(Logic::And, closure)
+    }
+
+    /// Add an OR group of conditions built by the closure, wrapped in parentheses.
+    pub fn or_on_closure<F>(self, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_closure(Logic::Or, closure)
+    }
+
+    fn add_closure<F>(mut self, logic: Logic, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.closures
+            .push((logic, closure(JoinClause::new()).conditions));
+
+        self
+    }
+
+    fn add_column(
+        self,
+        left_hand: &str,
+        operator: Operator,
+        right_hand: &str,
+        logic: Logic,
+    ) -> Self {
+        self.add_condition(
+            left_hand,
+            operator,
+            logic,
+            Box::new(Column(right_hand.to_string())),
+        )
+    }
+
+    fn add_condition(
+        mut self,
+        field: &str,
+        operator: Operator,
+        logic: Logic,
+        value: Box<dyn ToSql>,
+    ) -> Self {
+        // comparing with a missing optional value becomes a NULL check
+        let (operator, values) = match operator {
+            Operator::Equal if value.is_null() => (Operator::IsNull, Vec::new()),
+            Operator::NotEqual if value.is_null() => (Operator::IsNotNull, Vec::new()),
+            _ => (operator, vec![value]),
+        };
+
+        self.conditions
+            .push(Condition::new(field, operator, logic, values));
+
+        self
+    }
+}
+
+impl Default for JoinClause {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,20 +2,23 @@
 //!
 //! The core library for building SQL queries. This library is used by the [Eloquent](https://crates.io/crates/eloquent) library to build SQL queries.
 
-use compiler::{build_statement, build_substatement};
+use compiler::{build_statement, compile_statement, compile_substatement};
 use error::EloquentError;
 use std::fmt::Display;
 
 mod builders;
+mod case_builder;
 mod checks;
 mod compiler;
 mod compilers;
 /// The error module that contains all the possible errors that can occur while building a query.
 pub mod error;
+mod join_clause;
 mod queries;
 mod query_builder;
 mod subqueries;
 mod subquery_builder;
+mod transaction_block;
 mod validator;
 
 /// The main builder struct that holds all the query building information.
@@ -23,18 +26,53 @@ pub struct QueryBuilder {
     table: Option<String>,
     selects: Vec<Select>,
     inserts: Vec<Insert>,
+    insert_rows: Vec<Vec<Insert>>,
+    on_duplicate_key_updates: Vec<Assignment>,
+    conflict_target: Option<Vec<String>>,
+    inserted_flag: Option<String>,
+    returning: Vec<String>,
+    upsert: Option<Upsert>,
     updates: Vec<Update>,
+    update_from: Option<UpdateFrom>,
     delete: bool,
     conditions: Vec<Condition>,
     closures: Vec<(Logic, Vec<Condition>)>,
     joins: Vec<Join>,
     havings: Vec<Having>,
     group_by: Vec<String>,
+    // expressions in group_by that are not validated against the selects
+    raw_group_by: Vec<String>,
     order_by: Vec<OrderColumn>,
     limit: Option<u64>,
     offset: Option<u64>,
+    // aliases of the CASE expressions built without any branches
+    empty_cases: Vec<String>,
+    // set when insert_many received no rows or a row without columns
+    empty_insert: bool,
     enable_checks: bool,
+    strict_bindings: bool,
+    multiple_statements: bool,
+    dedupe_bindings: bool,
+    annotate: bool,
+    auto_group_by: bool,
+    rollup: bool,
     paginate: Option<Paginate>,
+    cursor: Option<Condition>,
+    shards: Option<Shards>,
+    lock: Option<Lock>,
+    lock_wait: Option<LockWait>,
+    explain: Option<ExplainOptions>,
+    merge: Option<Merge>,
+    from_values: Option<FromValues>,
+    from_function: Option<FromFunction>,
+    from_subquery: Option<FromSubquery>,
+    unions: Vec<Union>,
+    ctes: Vec<Cte>,
+    tags: Vec<(String, String)>,
+    distinct_on: Vec<String>,
+    dialect: Dialect,
+    use_as_keyword: bool,
+    quote_identifiers: bool,
 }
 
 /// The subquery builder struct that holds all the subquery building information.
@@ -50,12 +88,54 @@ pub struct SubqueryBuilder {
     offset: Option<u64>,
 }
 
+/// A list of queries wrapped in a single transaction.
+pub struct TransactionBlock {
+    queries: Vec<QueryBuilder>,
+}
+
+/// The `WHEN ... THEN ...` branches of a `CASE` expression, see [`QueryBuilder::select_case`].
+pub struct CaseBuilder {
+    whens: Vec<CaseWhen>,
+    else_value: Option<Box<dyn ToSql>>,
+}
+
+struct CaseWhen {
+    column: String,
+    value: Box<dyn ToSql>,
+    result: Box<dyn ToSql>,
+}
+
+/// The conditions of a join's `ON` clause, see [`QueryBuilder::join_on`].
+pub struct JoinClause {
+    conditions: Vec<Condition>,
+    closures: Vec<(Logic, Vec<Condition>)>,
+}
+
+/// The SQL of a nested query together with the values bound to its placeholders.
+pub type CompiledSql<'a> = (String, Vec<&'a dyn ToSql>);
+
 pub trait ToSql {
     fn to_sql(&self) -> Result<String, EloquentError>;
 
     fn is_subquery(&self) -> bool {
         false
     }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    /// The value bound to a placeholder in a parameterized query, or `None` when the SQL is inlined.
+    fn to_variable(&self) -> Option<Variable> {
+        None
+    }
+
+    /// The compiled SQL and values of a nested query, whose values are bound like the outer ones.
+    ///
+    /// The format carries the dialect and identifier quoting of the outer query.
+    fn compile(&self, _format: IdentifierFormat) -> Option<Result<CompiledSql<'_>, EloquentError>> {
+        None
+    }
 }
 
 pub trait Columnable {
@@ -70,33 +150,141 @@ pub(crate) trait PerformChecks {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError>;
 }
 
-#[allow(clippy::borrowed_box)]
 pub(crate) trait SqlBuilder {
     fn build<'a>(
         builder: &'a QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<(dyn ToSql + 'static)>>,
+        params: &mut Vec<&'a dyn ToSql>,
     ) -> Result<String, EloquentError>;
 }
 
+/// A value bound to a placeholder of a parameterized query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Variable {
+    String(String),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    Null,
+}
+
+impl From<i8> for Variable {
+    fn from(value: i8) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<i16> for Variable {
+    fn from(value: i16) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<i32> for Variable {
+    fn from(value: i32) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<i64> for Variable {
+    fn from(value: i64) -> Self {
+        Variable::Int(value)
+    }
+}
+
+impl From<u8> for Variable {
+    fn from(value: u8) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<u16> for Variable {
+    fn from(value: u16) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<u32> for Variable {
+    fn from(value: u32) -> Self {
+        Variable::Int(value as i64)
+    }
+}
+
+impl From<u64> for Variable {
+    fn from(value: u64) -> Self {
+        Variable::UInt(value)
+    }
+}
+
+impl From<&str> for Variable {
+    fn from(value: &str) -> Self {
+        Variable::String(value.to_string())
+    }
+}
+
+impl From<String> for Variable {
+    fn from(value: String) -> Self {
+        Variable::String(value)
+    }
+}
+
+impl From<bool> for Variable {
+    fn from(value: bool) -> Self {
+        Variable::Bool(value)
+    }
+}
+
+impl<T: Into<Variable>> From<Option<T>> for Variable {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Variable::Null,
+        }
+    }
+}
+
+impl From<f32> for Variable {
+    fn from(value: f32) -> Self {
+        // widen through the decimal representation so 9.99f32 stays 9.99 instead of 9.989999771118164
+        Variable::Float(value.to_string().parse().unwrap_or(value as f64))
+    }
+}
+
+impl From<f64> for Variable {
+    fn from(value: f64) -> Self {
+        Variable::Float(value)
+    }
+}
+
 pub(crate) enum Action {
     Select,
     Insert,
     Update,
     Delete,
+    Merge,
 }
 
-struct Condition {
+/// A where condition built outside of the query, see [`QueryBuilder::add_conditions`].
+pub struct Condition {
     field: String,
     operator: Operator,
     logic: Logic,
     values: Vec<Box<dyn ToSql>>,
+    collation: Option<String>,
 }
 
 struct Select {
     column: String,
     function: Option<Function>,
     alias: Option<String>,
+    values: Vec<Box<dyn ToSql>>,
+    window: Option<Window>,
+}
+
+struct Window {
+    partition_by: Vec<String>,
+    order_by: Vec<(String, Order)>,
 }
 
 struct Insert {
@@ -106,21 +294,103 @@ struct Insert {
 
 struct Update {
     column: String,
+    /// The arithmetic operator applied to the current value, for increments and decrements.
+    operator: Option<&'static str>,
     value: Box<dyn ToSql>,
 }
 
-#[derive(PartialEq)]
+struct UpdateFrom {
+    table: String,
+    condition: Condition,
+}
+
+struct Assignment {
+    column: String,
+    expression: String,
+    values: Vec<Box<dyn ToSql>>,
+}
+
+struct Column(String);
+
+/// A string that is always rendered inline, where MySQL only accepts a string literal.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Literal(String);
+
+/// An identifier that is always quoted with the quote character of the dialect, even without `quote_identifiers()`.
+///
+/// ```
+/// use eloquent_core::{QueryBuilder, Quoted};
+///
+/// let result = QueryBuilder::new()
+///     .table(Quoted("flight log"))
+///     .select(vec!["origin", "destination"])
+///     .select(Quoted("departure time"));
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT origin, destination, `departure time` FROM `flight log`"
+/// );
+/// ```
+pub struct Quoted<'a>(pub &'a str);
+
+// quoted identifiers are marked with private use characters and quoted once the dialect is known
+const QUOTED_START: char = '\u{E000}';
+const QUOTED_END: char = '\u{E001}';
+
+// clause annotations are marked the same way and only rendered as comments in inlined SQL
+const ANNOTATION_START: char = '\u{E002}';
+const ANNOTATION_END: char = '\u{E003}';
+
+/// A reference to the value that would have been inserted into a column, rendered as `VALUES(column)`.
+///
+/// Used in the `ON DUPLICATE KEY UPDATE` clause of a MySQL upsert.
+pub struct Values<T>(pub T);
+
+/// A reference to the value proposed for insertion, rendered as `EXCLUDED.column`.
+///
+/// Used in the `ON CONFLICT ... DO UPDATE` clause of a Postgres or SQLite upsert.
+pub struct Excluded<T>(pub T);
+
+/// A value cast to a SQL type, rendered as `value::type` in Postgres and `CAST(value AS type)` elsewhere.
+///
+/// The value is still bound to a placeholder when the query is parameterized.
+pub struct Cast<T>(pub T, pub &'static str);
+
 struct OrderColumn {
     column: String,
     order: Order,
+    nulls: Option<Nulls>,
+    kind: OrderKind,
+    values: Vec<Box<dyn ToSql>>,
+}
+
+/// Raw and random orderings are rendered without a direction.
+enum OrderKind {
+    Column,
+    Raw,
+    Random,
 }
 
 struct Having {
     conditions: Vec<Condition>,
+    compare_columns: bool,
 }
 
+/// The comparison operator of a condition between a column and a value or another column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Like,
+}
+
+/// The operator of a condition.
 #[derive(Debug, PartialEq)]
-enum Operator {
+pub(crate) enum Operator {
     Equal,
     NotEqual,
     GreaterThan,
@@ -128,7 +398,10 @@ enum Operator {
     LessThan,
     LessThanOrEqual,
     Between,
+    NotBetween,
     Like,
+    LikeEscaped,
+    NotLike,
     In,
     NotIn,
     IsNull,
@@ -137,6 +410,17 @@ enum Operator {
     Year,
     Month,
     Day,
+    WithinLast(u32, IntervalUnit),
+    HasFlag,
+    ArrayOverlaps,
+    ArrayContains,
+    TimeBetween,
+    Exists,
+    NotExists,
+    Raw,
+    InSet,
+    WithinDistance,
+    Overlaps,
 }
 
 #[derive(Debug, PartialEq)]
@@ -153,6 +437,12 @@ enum Function {
     Min,
     Max,
     Distinct,
+    Grouping,
+    Concat(Vec<String>),
+    CountDistinct,
+    SumDistinct,
+    AvgDistinct,
+    GroupConcat { separator: Literal, distinct: bool },
 }
 
 struct Join {
@@ -160,52 +450,323 @@ struct Join {
     left_hand: String,
     join_type: JoinType,
     right_hand: String,
+    conditions: Vec<Condition>,
+    closures: Vec<(Logic, Vec<Condition>)>,
+    subquery: Option<SubqueryBuilder>,
+}
+
+/// The SQL dialect used to render database specific syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Dialect {
+    #[default]
+    MySql,
+    Postgres,
+    Sqlite,
+    /// SQL Server, which limits rows with `OFFSET .. FETCH` and renders booleans as `1` and `0`.
+    Mssql,
+    /// Portable ANSI SQL, with every identifier double-quoted.
+    AnsiStandard,
 }
 
-enum JoinType {
+/// The unit of time used in interval arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntervalUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// The type of join used to combine two tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinType {
     Inner,
     Left,
     Right,
     Full,
+    Cross,
 }
 
-#[derive(Debug, PartialEq)]
-pub(crate) enum Order {
+/// The direction of an ordering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Order {
     Asc,
     Desc,
 }
 
+/// The options of a Postgres `EXPLAIN`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ExplainOptions {
+    pub analyze: bool,
+    pub buffers: bool,
+    pub format: Option<ExplainFormat>,
+}
+
+/// The output format of a Postgres `EXPLAIN`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExplainFormat {
+    Text,
+    Json,
+    Xml,
+    Yaml,
+}
+
+/// The position of null values in an ordering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Nulls {
+    First,
+    Last,
+}
+
 pub(crate) struct Paginate {
     column: String,
     last_id: Option<Box<dyn ToSql>>,
     per_page: u64,
 }
 
+pub(crate) struct Lock {
+    mode: LockMode,
+    tables: Vec<String>,
+}
+
+pub(crate) enum LockMode {
+    Update,
+    Share,
+}
+
+pub(crate) enum LockWait {
+    NoWait,
+    SkipLocked,
+}
+
+pub(crate) struct FromValues {
+    rows: Vec<Vec<Box<dyn ToSql>>>,
+    alias: String,
+    columns: Vec<String>,
+}
+
+pub(crate) struct FromSubquery {
+    query: Box<dyn ToSql>,
+    alias: String,
+}
+
+pub(crate) struct Cte {
+    name: String,
+    // several queries are combined with UNION ALL
+    queries: Vec<SubqueryBuilder>,
+    recursive: bool,
+}
+
+pub(crate) struct Union {
+    all: bool,
+    query: QueryBuilder,
+}
+
+pub(crate) struct Upsert {
+    conflict_columns: Vec<String>,
+    update_columns: Vec<String>,
+}
+
+pub(crate) struct FromFunction {
+    name: String,
+    args: Vec<Box<dyn ToSql>>,
+    alias: String,
+    columns: Vec<String>,
+    with_ordinality: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct Merge {
+    source: Option<String>,
+    on: Option<(String, String)>,
+    matched_updates: Vec<(String, String)>,
+    not_matched_insert: Option<(Vec<String>, Vec<String>)>,
+}
+
+pub(crate) struct Shards {
+    count: u32,
+    suffix: Box<dyn Fn(u32) -> String>,
+}
+
+/// How identifiers such as tables, aliases and columns are rendered.
+#[derive(Clone, Copy)]
+pub struct IdentifierFormat {
+    use_as_keyword: bool,
+    quote: Option<Dialect>,
+    dialect: Dialect,
+}
+
+impl Default for IdentifierFormat {
+    fn default() -> Self {
+        Self {
+            use_as_keyword: true,
+            quote: None,
+            dialect: Dialect::default(),
+        }
+    }
+}
+
+impl IdentifierFormat {
+    fn identifier(&self, name: &str) -> String {
+        if is_quoted(name) {
+            return name.to_string();
+        }
+
+        match self.quote {
+            Some(dialect) => dialect.quote_identifier(name),
+            None => name.to_string(),
+        }
+    }
+
+    fn alias(&self, expression: &str, alias: &str) -> String {
+        if self.use_as_keyword {
+            format!("{} AS {}", expression, self.identifier(alias))
+        } else {
+            format!("{} {}", expression, self.identifier(alias))
+        }
+    }
+
+    fn table(&self, table: &str) -> String {
+        if (self.use_as_keyword && self.quote.is_none()) || is_quoted(table) {
+            return table.to_string();
+        }
+
+        match split_table_alias(table) {
+            Some((name, Some(alias))) => self.alias(&self.identifier(name), alias),
+            Some((name, None)) => self.identifier(name),
+            None => self.identifier(table),
+        }
+    }
+}
+
+fn is_quoted(identifier: &str) -> bool {
+    identifier.starts_with(QUOTED_START) && identifier.ends_with(QUOTED_END)
+}
+
+/// Replace every identifier marked by `Quoted` with the quoted identifier of the dialect.
+fn resolve_quoted(sql: &str, dialect: Dialect) -> String {
+    let mut resolved = String::with_capacity(sql.len());
+    let mut rest = sql;
+
+    while let Some(start) = rest.find(QUOTED_START) {
+        let Some(length) = rest[start..].find(QUOTED_END) else {
+            break;
+        };
+
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(
+            &dialect.quote_identifier(&rest[start + QUOTED_START.len_utf8()..start + length]),
+        );
+        rest = &rest[start + length + QUOTED_END.len_utf8()..];
+    }
+
+    resolved.push_str(rest);
+    resolved
+}
+
+/// Replace every clause annotation with a `-- label` comment on its own line, or remove it.
+fn resolve_annotations(sql: &str, render: bool) -> String {
+    let mut resolved = String::with_capacity(sql.len());
+    let mut rest = sql;
+
+    while let Some(start) = rest.find(ANNOTATION_START) {
+        let Some(length) = rest[start..].find(ANNOTATION_END) else {
+            break;
+        };
+
+        let label = &rest[start + ANNOTATION_START.len_utf8()..start + length];
+
+        resolved.push_str(&rest[..start]);
+        rest = &rest[start + length + ANNOTATION_END.len_utf8()..];
+
+        // the comment ends at the line break, so it takes the place of the space before the clause
+        if render {
+            resolved.push_str(&format!("\n-- {}\n", label));
+            rest = rest.strip_prefix(' ').unwrap_or(rest);
+        }
+    }
+
+    resolved.push_str(rest);
+    resolved
+}
+
+/// Split a `table`, `table alias` or `table AS alias` reference into its name and alias.
+fn split_table_alias(table: &str) -> Option<(&str, Option<&str>)> {
+    match table.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        [name] => Some((name, None)),
+        [name, alias] => Some((name, Some(alias))),
+        [name, keyword, alias] if keyword.eq_ignore_ascii_case("as") => Some((name, Some(alias))),
+        _ => None,
+    }
+}
+
 impl Select {
-    fn format_column_name(&self) -> String {
+    fn format_column_name(&self, format: IdentifierFormat) -> String {
         let column = match &self.function {
-            Some(function) => match function {
-                Function::Distinct => format!("{} {}", function, self.column),
-                _ => format!("{}({})", function, self.column),
-            },
-            None => self.column.clone(),
+            Some(Function::Concat(parts)) => format
+                .dialect
+                .concat(&parts.iter().map(String::as_str).collect::<Vec<&str>>()),
+            Some(Function::GroupConcat { distinct, .. }) => {
+                format.dialect.group_concat(&self.column, *distinct)
+            }
+            _ => self.format_column_name_without_alias(),
         };
 
         if let Some(alias) = &self.alias {
-            format!("{} AS {}", column, alias)
+            format.alias(&column, alias)
         } else {
             column
         }
     }
 
+    fn is_null_placeholder(&self) -> bool {
+        self.function.is_none() && self.column == "NULL"
+    }
+
+    fn is_aggregate(&self) -> bool {
+        // a windowed aggregate is computed per row and does not group the result
+        self.window.is_none()
+            && matches!(&self.function, Some(function) if !matches!(function, Function::Distinct | Function::Concat(_)))
+    }
+
     fn format_column_name_without_alias(&self) -> String {
-        match &self.function {
+        let column = match &self.function {
             Some(function) => match function {
                 Function::Distinct => format!("{} {}", function, self.column),
+                Function::CountDistinct | Function::SumDistinct | Function::AvgDistinct => {
+                    format!("{}(DISTINCT {})", function, self.column)
+                }
                 _ => format!("{}({})", function, self.column),
             },
             None => self.column.clone(),
+        };
+
+        let Some(window) = &self.window else {
+            return column;
+        };
+
+        let mut clauses = Vec::new();
+
+        if !window.partition_by.is_empty() {
+            clauses.push(format!("PARTITION BY {}", window.partition_by.join(", ")));
         }
+
+        if !window.order_by.is_empty() {
+            clauses.push(format!(
+                "ORDER BY {}",
+                window
+                    .order_by
+                    .iter()
+                    .map(|(column, order)| format!("{} {}", column, order))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+        }
+
+        format!("{} OVER ({})", column, clauses.join(" "))
     }
 }
 
@@ -215,6 +776,12 @@ impl Selectable for &str {
     }
 }
 
+impl Selectable for Quoted<'_> {
+    fn to_select_column(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Selectable for SubqueryBuilder {
     fn to_select_column(&self) -> String {
         self.to_sql().unwrap()
@@ -222,12 +789,23 @@ impl Selectable for SubqueryBuilder {
 }
 
 impl Condition {
+    /// A condition joined to the previous conditions with `AND`.
+    pub fn and(field: &str, operator: Comparison, value: impl ToSql + 'static) -> Self {
+        Condition::new(field, operator.into(), Logic::And, vec![Box::new(value)])
+    }
+
+    /// A condition joined to the previous conditions with `OR`.
+    pub fn or(field: &str, operator: Comparison, value: impl ToSql + 'static) -> Self {
+        Condition::new(field, operator.into(), Logic::Or, vec![Box::new(value)])
+    }
+
     fn new(field: &str, operator: Operator, logic: Logic, values: Vec<Box<dyn ToSql>>) -> Self {
         Condition {
             field: field.to_string(),
             operator,
             logic,
             values,
+            collation: None,
         }
     }
 }
@@ -236,53 +814,140 @@ impl ToSql for &str {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(format!("'{}'", self.replace('\'', "''")))
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::String(self.to_string()))
+    }
 }
 
 impl ToSql for String {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(format!("'{}'", self.replace('\'', "''")))
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::String(self.clone()))
+    }
 }
 
 impl ToSql for &String {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(format!("'{}'", self.replace('\'', "''")))
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::String(self.to_string()))
+    }
+}
+
+impl ToSql for i8 {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.to_string())
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
+}
+
+impl ToSql for i16 {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.to_string())
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
 }
 
 impl ToSql for i32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
 }
 
 impl ToSql for i64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self))
+    }
+}
+
+impl ToSql for u8 {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.to_string())
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
+}
+
+impl ToSql for u16 {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.to_string())
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
 }
 
 impl ToSql for u32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Int(*self as i64))
+    }
 }
 
 impl ToSql for u64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::UInt(*self))
+    }
 }
 
+// floats are rendered with their shortest round-trip representation and whole numbers keep a
+// trailing `.0`, so `5.0` stays a decimal literal instead of becoming the integer `5`
 impl ToSql for f32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
-        Ok(self.to_string())
+        if !self.is_finite() {
+            return Err(EloquentError::NonFiniteFloat(self.to_string()));
+        }
+
+        Ok(format!("{:?}", self))
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::from(*self))
     }
 }
 
 impl ToSql for f64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
-        Ok(self.to_string())
+        // NaN and infinity have no literal in SQL
+        if !self.is_finite() {
+            return Err(EloquentError::NonFiniteFloat(self.to_string()));
+        }
+
+        Ok(format!("{:?}", self))
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Float(*self))
     }
 }
 
@@ -290,22 +955,134 @@ impl ToSql for bool {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(Variable::Bool(*self))
+    }
+}
+
+impl<T: ToSql> ToSql for Option<T> {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        match self {
+            Some(value) => value.to_sql(),
+            None => Ok("NULL".to_string()),
+        }
+    }
+
+    fn is_subquery(&self) -> bool {
+        matches!(self, Some(value) if value.is_subquery())
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        match self {
+            Some(value) => value.to_variable(),
+            None => Some(Variable::Null),
+        }
+    }
+
+    fn compile(&self, format: IdentifierFormat) -> Option<Result<CompiledSql<'_>, EloquentError>> {
+        self.as_ref().and_then(|value| value.compile(format))
+    }
+}
+
+impl ToSql for Variable {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        match self {
+            Variable::String(value) => value.to_sql(),
+            Variable::Int(value) => value.to_sql(),
+            Variable::UInt(value) => value.to_sql(),
+            Variable::Float(value) => value.to_sql(),
+            Variable::Bool(value) => value.to_sql(),
+            Variable::Null => Ok("NULL".to_string()),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        *self == Variable::Null
+    }
+
+    fn to_variable(&self) -> Option<Variable> {
+        Some(self.clone())
+    }
+}
+
+impl ToSql for Column {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.0.clone())
+    }
+}
+
+impl ToSql for Literal {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(Dialect::MySql.quote_string(&self.0))
+    }
+}
+
+impl<T: Display> ToSql for Values<T> {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(format!("VALUES({})", self.0))
+    }
+}
+
+impl<T: Display> ToSql for Excluded<T> {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(format!("EXCLUDED.{}", self.0))
+    }
+}
+
+impl<T: ToSql> ToSql for Cast<T> {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(format!("CAST({} AS {})", self.0.to_sql()?, self.1))
+    }
+
+    fn compile(&self, format: IdentifierFormat) -> Option<Result<CompiledSql<'_>, EloquentError>> {
+        let sql = match format.dialect {
+            Dialect::Postgres => format!("?::{}", self.1),
+            _ => format!("CAST(? AS {})", self.1),
+        };
+
+        Some(Ok((sql, vec![&self.0 as &dyn ToSql])))
+    }
 }
 
 impl ToSql for QueryBuilder {
     fn to_sql(&self) -> Result<String, EloquentError> {
         build_statement(self)
     }
+
+    fn compile(&self, _format: IdentifierFormat) -> Option<Result<CompiledSql<'_>, EloquentError>> {
+        let mut params = Vec::new();
+
+        // nested queries keep the dialect they were built with, which union members inherit from the outer query,
+        // and are compiled without their annotations, which would break the outer layout
+        Some(
+            compile_statement(self, &mut params)
+                .map(|sql| (resolve_annotations(&sql, false), params)),
+        )
+    }
 }
 
 impl ToSql for SubqueryBuilder {
     fn to_sql(&self) -> Result<String, EloquentError> {
-        build_substatement(self)
+        self.scalar_sql()
     }
 
     fn is_subquery(&self) -> bool {
         true
     }
+
+    fn compile(&self, format: IdentifierFormat) -> Option<Result<CompiledSql<'_>, EloquentError>> {
+        let mut params = Vec::new();
+
+        Some(
+            compile_substatement(self, format, &mut params)
+                .map(|sql| (format!("({})", sql), params)),
+        )
+    }
 }
 
 impl Columnable for &str {
@@ -314,12 +1091,32 @@ impl Columnable for &str {
     }
 }
 
+impl Columnable for Quoted<'_> {
+    fn to_columns(&self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
 impl Columnable for Vec<&str> {
     fn to_columns(&self) -> Vec<String> {
         self.iter().map(|&s| s.to_string()).collect()
     }
 }
 
+impl From<Comparison> for Operator {
+    fn from(comparison: Comparison) -> Self {
+        match comparison {
+            Comparison::Equal => Operator::Equal,
+            Comparison::NotEqual => Operator::NotEqual,
+            Comparison::GreaterThan => Operator::GreaterThan,
+            Comparison::GreaterThanOrEqual => Operator::GreaterThanOrEqual,
+            Comparison::LessThan => Operator::LessThan,
+            Comparison::LessThanOrEqual => Operator::LessThanOrEqual,
+            Comparison::Like => Operator::Like,
+        }
+    }
+}
+
 impl Display for Operator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let operator = match self {
@@ -330,7 +1127,9 @@ impl Display for Operator {
             Operator::LessThan => "<",
             Operator::LessThanOrEqual => "<=",
             Operator::Between => "BETWEEN",
-            Operator::Like => "LIKE",
+            Operator::NotBetween => "NOT BETWEEN",
+            Operator::Like | Operator::LikeEscaped => "LIKE",
+            Operator::NotLike => "NOT LIKE",
             Operator::In => "IN",
             Operator::NotIn => "NOT IN",
             Operator::IsNull => "IS NULL",
@@ -339,12 +1138,29 @@ impl Display for Operator {
             Operator::Year => "YEAR",
             Operator::Month => "MONTH",
             Operator::Day => "DAY",
+            Operator::WithinLast(_, _) => ">=",
+            Operator::HasFlag => "&",
+            Operator::ArrayOverlaps => "&&",
+            Operator::ArrayContains => "@>",
+            Operator::TimeBetween => "BETWEEN",
+            Operator::Exists => "EXISTS",
+            Operator::NotExists => "NOT EXISTS",
+            Operator::Raw => "",
+            Operator::InSet => "FIND_IN_SET",
+            Operator::WithinDistance => "ST_DWithin",
+            Operator::Overlaps => "OVERLAPS",
         };
 
         write!(f, "{}", operator)
     }
 }
 
+impl Display for Quoted<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", QUOTED_START, self.0, QUOTED_END)
+    }
+}
+
 impl Display for Order {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let order = match self {
@@ -356,6 +1172,52 @@ impl Display for Order {
     }
 }
 
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self {
+            ExplainFormat::Text => "TEXT",
+            ExplainFormat::Json => "JSON",
+            ExplainFormat::Xml => "XML",
+            ExplainFormat::Yaml => "YAML",
+        };
+
+        write!(f, "{}", format)
+    }
+}
+
+impl Display for LockMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = match self {
+            LockMode::Update => "FOR UPDATE",
+            LockMode::Share => "FOR SHARE",
+        };
+
+        write!(f, "{}", mode)
+    }
+}
+
+impl Display for LockWait {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let wait = match self {
+            LockWait::NoWait => "NOWAIT",
+            LockWait::SkipLocked => "SKIP LOCKED",
+        };
+
+        write!(f, "{}", wait)
+    }
+}
+
+impl Display for Nulls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nulls = match self {
+            Nulls::First => "NULLS FIRST",
+            Nulls::Last => "NULLS LAST",
+        };
+
+        write!(f, "{}", nulls)
+    }
+}
+
 impl Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let function = match self {
@@ -365,12 +1227,133 @@ impl Display for Function {
             Function::Min => "MIN",
             Function::Max => "MAX",
             Function::Distinct => "DISTINCT",
+            Function::Grouping => "GROUPING",
+            Function::Concat(_) => "CONCAT",
+            Function::CountDistinct => "COUNT",
+            Function::SumDistinct => "SUM",
+            Function::AvgDistinct => "AVG",
+            Function::GroupConcat { .. } => "GROUP_CONCAT",
         };
 
         write!(f, "{}", function)
     }
 }
 
+impl Operator {
+    fn is_postgres_only(&self) -> bool {
+        matches!(self, Operator::ArrayOverlaps | Operator::ArrayContains)
+    }
+}
+
+impl Dialect {
+    fn concat(&self, parts: &[&str]) -> String {
+        match self {
+            Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard => parts.join(" || "),
+            Dialect::MySql | Dialect::Mssql => format!("CONCAT({})", parts.join(", ")),
+        }
+    }
+
+    /// The separator is bound to a placeholder, which MySQL renders inline since it only accepts a string literal.
+    fn group_concat(&self, column: &str, distinct: bool) -> String {
+        let expression = match distinct {
+            true => format!("DISTINCT {}", column),
+            false => column.to_string(),
+        };
+
+        match self {
+            Dialect::MySql => format!("GROUP_CONCAT({} SEPARATOR ?)", expression),
+            Dialect::Sqlite => format!("GROUP_CONCAT({}, ?)", expression),
+            Dialect::Postgres | Dialect::Mssql => format!("STRING_AGG({}, ?)", expression),
+            Dialect::AnsiStandard => format!(
+                "LISTAGG({}, ?) WITHIN GROUP (ORDER BY {})",
+                expression, column
+            ),
+        }
+    }
+
+    /// A string literal, with backslashes escaped in MySQL where they start an escape sequence.
+    fn quote_string(&self, value: &str) -> String {
+        match self {
+            Dialect::MySql => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''")),
+            Dialect::Postgres | Dialect::Sqlite | Dialect::Mssql | Dialect::AnsiStandard => {
+                format!("'{}'", value.replace('\'', "''"))
+            }
+        }
+    }
+
+    fn format_time(&self, field: &str) -> String {
+        match self {
+            Dialect::MySql | Dialect::Sqlite => format!("TIME({})", field),
+            Dialect::Postgres | Dialect::Mssql | Dialect::AnsiStandard => {
+                format!("CAST({} AS TIME)", field)
+            }
+        }
+    }
+
+    fn quote_identifier(&self, name: &str) -> String {
+        let (open, close) = match self {
+            Dialect::MySql => ('`', '`'),
+            Dialect::Postgres | Dialect::Sqlite | Dialect::AnsiStandard => ('"', '"'),
+            Dialect::Mssql => ('[', ']'),
+        };
+
+        name.split('.')
+            .map(|part| match part {
+                "*" => part.to_string(),
+                _ => format!(
+                    "{}{}{}",
+                    open,
+                    part.replace(close, &format!("{}{}", close, close)),
+                    close
+                ),
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+}
+
+impl IntervalUnit {
+    fn name(&self) -> &str {
+        match self {
+            IntervalUnit::Seconds => "second",
+            IntervalUnit::Minutes => "minute",
+            IntervalUnit::Hours => "hour",
+            IntervalUnit::Days => "day",
+            IntervalUnit::Weeks => "week",
+            IntervalUnit::Months => "month",
+            IntervalUnit::Years => "year",
+        }
+    }
+
+    fn format_ago(&self, amount: u32, dialect: Dialect) -> String {
+        let plural = if amount == 1 { "" } else { "s" };
+
+        match dialect {
+            Dialect::MySql => format!(
+                "DATE_SUB(NOW(), INTERVAL {} {})",
+                amount,
+                self.name().to_uppercase()
+            ),
+            Dialect::Postgres => format!("NOW() - INTERVAL '{} {}{}'", amount, self.name(), plural),
+            Dialect::Sqlite => match self {
+                // SQLite has no week modifier, so weeks are expressed in days
+                IntervalUnit::Weeks => format!("DATETIME('now', '-{} days')", amount * 7),
+                _ => format!("DATETIME('now', '-{} {}{}')", amount, self.name(), plural),
+            },
+            Dialect::Mssql => format!("DATEADD({}, -{}, GETDATE())", self.name(), amount),
+            Dialect::AnsiStandard => match self {
+                // standard SQL has no week interval field, so weeks are expressed in days
+                IntervalUnit::Weeks => format!("CURRENT_TIMESTAMP - INTERVAL '{}' DAY", amount * 7),
+                _ => format!(
+                    "CURRENT_TIMESTAMP - INTERVAL '{}' {}",
+                    amount,
+                    self.name().to_uppercase()
+                ),
+            },
+        }
+    }
+}
+
 impl Display for JoinType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let join_type = match self {
@@ -378,6 +1361,7 @@ impl Display for JoinType {
             JoinType::Left => "LEFT JOIN",
             JoinType::Right => "RIGHT JOIN",
             JoinType::Full => "FULL JOIN",
+            JoinType::Cross => "CROSS JOIN",
         };
 
         write!(f, "{}", join_type)
@@ -385,21 +1369,27 @@ impl Display for JoinType {
 }
 
 impl Condition {
-    fn format_sql(&self) -> String {
-        let values = self
-            .values
-            .iter()
-            .map(|v| v.to_sql().unwrap())
-            .collect::<Vec<String>>()
-            .join(", ");
+    fn format_sql(&self, dialect: Dialect) -> Result<String, EloquentError> {
+        if self.operator.is_postgres_only() && dialect != Dialect::Postgres {
+            return Err(EloquentError::UnsupportedByDialect(
+                self.operator.to_string(),
+            ));
+        }
 
-        match self.operator {
-            Operator::Between => format!(
-                "{} {} {} AND {}",
-                self.field,
-                self.operator,
-                values.split(", ").next().unwrap(),
-                values.split(", ").last().unwrap()
+        if self.operator == Operator::Raw && self.values.len() > self.field.matches('?').count() {
+            return Err(EloquentError::UnusedBindings(self.field.clone()));
+        }
+
+        let values = vec!["?"; self.values.len()].join(", ");
+
+        let sql = match self.operator {
+            Operator::Between | Operator::NotBetween => {
+                format!("{} {} ? AND ?", self.field, self.operator)
+            }
+            Operator::TimeBetween => format!(
+                "{} {} ? AND ?",
+                dialect.format_time(&self.field),
+                self.operator
             ),
             Operator::In | Operator::NotIn => {
                 if self.values.iter().any(|v| v.is_subquery()) {
@@ -413,7 +1403,72 @@ impl Condition {
             Operator::Date | Operator::Year | Operator::Month | Operator::Day => {
                 format!("{}({}) = {}", self.operator, self.field, values)
             }
+            Operator::WithinLast(amount, unit) => format!(
+                "{} {} {}",
+                self.field,
+                self.operator,
+                unit.format_ago(amount, dialect)
+            ),
+            Operator::ArrayOverlaps | Operator::ArrayContains => {
+                format!("{} {} ARRAY[{}]", self.field, self.operator, values)
+            }
+            // the subquery already contains parentheses
+            Operator::Exists | Operator::NotExists => format!("{} {}", self.operator, values),
+            Operator::HasFlag => format!("({} {} ?) = ?", self.field, self.operator),
+            // a backslash would need escaping itself in MySQL string literals
+            Operator::LikeEscaped => format!("{} {} ? ESCAPE '!'", self.field, self.operator),
+            Operator::Raw => self.field.clone(),
+            // the field holds the start and end columns of the first period
+            Operator::Overlaps => match dialect {
+                Dialect::Postgres | Dialect::AnsiStandard => {
+                    format!("({}) {} ({})", self.field, self.operator, values)
+                }
+                _ => {
+                    return Err(EloquentError::UnsupportedByDialect(
+                        self.operator.to_string(),
+                    ))
+                }
+            },
+            // the distance is measured on the sphere in meters, with coordinates in WGS 84
+            Operator::WithinDistance => match dialect {
+                Dialect::Postgres => format!(
+                    "{}({}::geography, ST_SetSRID(ST_MakePoint(?, ?), 4326)::geography, ?)",
+                    self.operator, self.field
+                ),
+                Dialect::MySql => {
+                    format!("ST_Distance_Sphere({}, POINT(?, ?)) <= ?", self.field)
+                }
+                _ => {
+                    return Err(EloquentError::UnsupportedByDialect(
+                        self.operator.to_string(),
+                    ))
+                }
+            },
+            Operator::InSet => {
+                // wrapping both sides in commas only matches whole list items, and a plain substring
+                // search keeps `%` and `_` in the value literal
+                let list = dialect.concat(&["','", &self.field, "','"]);
+                let item = dialect.concat(&["','", "?", "','"]);
+
+                match dialect {
+                    Dialect::MySql => format!("{}(?, {}) > 0", self.operator, self.field),
+                    Dialect::Postgres | Dialect::AnsiStandard => {
+                        format!("POSITION({} IN {}) > 0", item, list)
+                    }
+                    Dialect::Sqlite => format!("INSTR({}, {}) > 0", list, item),
+                    Dialect::Mssql => format!("CHARINDEX({}, {}) > 0", item, list),
+                }
+            }
+            // row value comparison, e.g. (created_at, id) > (?, ?)
+            Operator::GreaterThan | Operator::LessThan if self.values.len() > 1 => {
+                format!("{} {} ({})", self.field, self.operator, values)
+            }
             _ => format!("{} {} {}", self.field, self.operator, values),
+        };
+
+        match &self.collation {
+            Some(collation) => Ok(format!("{} COLLATE {}", sql, collation)),
+            None => Ok(sql),
         }
     }
 }
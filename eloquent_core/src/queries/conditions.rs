@@ -1,4 +1,7 @@
-use crate::{Columnable, Condition, Logic, Operator, QueryBuilder, ToSql};
+use crate::{
+    Column, Columnable, Comparison, Condition, IntervalUnit, Logic, Operator, QueryBuilder,
+    SubqueryBuilder, ToSql,
+};
 
 impl QueryBuilder {
     fn add_condition(
@@ -8,6 +11,17 @@ impl QueryBuilder {
         logic: Logic,
         values: Vec<Box<dyn ToSql>>,
     ) -> Self {
+        // comparing with a missing optional value becomes a NULL check
+        let (operator, values) = match operator {
+            Operator::Equal if values.len() == 1 && values[0].is_null() => {
+                (Operator::IsNull, Vec::new())
+            }
+            Operator::NotEqual if values.len() == 1 && values[0].is_null() => {
+                (Operator::IsNotNull, Vec::new())
+            }
+            _ => (operator, values),
+        };
+
         self.conditions
             .push(Condition::new(field, operator, logic, values));
 
@@ -45,6 +59,21 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE id = (SELECT MAX(duration_in_min) AS max_duration_in_min FROM flights)"
     /// );
     /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let gate: Option<&str> = None;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("gate", gate);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE gate IS NULL"
+    /// );
+    /// ```
     pub fn r#where(self, field: &str, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
@@ -283,6 +312,58 @@ impl QueryBuilder {
         )
     }
 
+    /// Add a where condition comparing two columns to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_column(
+    ///         "flights.actual_departure",
+    ///         Comparison::GreaterThan,
+    ///         "flights.scheduled_departure",
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE flights.actual_departure > flights.scheduled_departure"
+    /// );
+    /// ```
+    pub fn where_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_condition(
+            left_hand,
+            operator.into(),
+            Logic::And,
+            vec![Box::new(Column(right_hand.to_string()))],
+        )
+    }
+
+    /// Add an OR where condition comparing two columns to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join("airports", "flights.origin_airport", "airports.code")
+    ///     .r#where("flights.status", "cancelled")
+    ///     .or_where_column("flights.destination_airport", Comparison::Equal, "airports.code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights JOIN airports ON flights.origin_airport = airports.code WHERE flights.status = 'cancelled' OR flights.destination_airport = airports.code"
+    /// );
+    /// ```
+    pub fn or_where_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_condition(
+            left_hand,
+            operator.into(),
+            Logic::Or,
+            vec![Box::new(Column(right_hand.to_string()))],
+        )
+    }
+
     /// Add a where between condition to the query.
     ///
     /// ```
@@ -340,6 +421,81 @@ impl QueryBuilder {
         )
     }
 
+    /// Add a where not between condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_not_between("flight_duration", 120, 180);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE flight_duration NOT BETWEEN 120 AND 180"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .where_closure(|query| {
+    ///         query
+    ///             .where_not_between("flight_duration", 1, 5)
+    ///             .or_where_between("delay", 10, 20)
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' AND (flight_duration NOT BETWEEN 1 AND 5 OR delay BETWEEN 10 AND 20)"
+    /// );
+    /// ```
+    pub fn where_not_between(
+        self,
+        field: &str,
+        min: impl ToSql + 'static,
+        max: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::NotBetween,
+            Logic::And,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    /// Add an OR where not between condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .or_where_not_between("flight_duration", 120, 180);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' OR flight_duration NOT BETWEEN 120 AND 180"
+    /// );
+    /// ```
+    pub fn or_where_not_between(
+        self,
+        field: &str,
+        min: impl ToSql + 'static,
+        max: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::NotBetween,
+            Logic::Or,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
     /// Add a where LIKE condition to the query.
     ///
     /// ```
@@ -377,6 +533,147 @@ impl QueryBuilder {
         self.add_condition(field, Operator::Like, Logic::Or, vec![Box::new(value)])
     }
 
+    /// Add a where NOT LIKE condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_not_like("airplane_type", "Airbus%");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE airplane_type NOT LIKE 'Airbus%'"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_not_like("airline", "Pilot's%");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE airline NOT LIKE 'Pilot''s%'"
+    /// );
+    /// ```
+    pub fn where_not_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::NotLike, Logic::And, vec![Box::new(value)])
+    }
+
+    /// Add an OR where NOT LIKE condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_like("airplane_type", "Airbus%")
+    ///     .or_where_not_like("airline", "KLM%");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE airplane_type LIKE 'Airbus%' OR airline NOT LIKE 'KLM%'"
+    /// );
+    /// ```
+    pub fn or_where_not_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::NotLike, Logic::Or, vec![Box::new(value)])
+    }
+
+    /// Add a where condition matching values that start with the prefix, rendered as `LIKE 'prefix%'`.
+    ///
+    /// Wildcards inside the prefix are escaped with `!`, adding an `ESCAPE '!'` clause.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_prefix("flight_number", "KL");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE flight_number LIKE 'KL%'"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_prefix("flight_number", "KL_1");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE flight_number LIKE 'KL!_1%' ESCAPE '!'"
+    /// );
+    /// ```
+    pub fn where_prefix(self, field: &str, prefix: &str) -> Self {
+        if !prefix.contains(['%', '_', '!']) {
+            return self.add_condition(
+                field,
+                Operator::Like,
+                Logic::And,
+                vec![Box::new(format!("{}%", prefix))],
+            );
+        }
+
+        let mut pattern = String::new();
+
+        for c in prefix.chars() {
+            if matches!(c, '%' | '_' | '!') {
+                pattern.push('!');
+            }
+
+            pattern.push(c);
+        }
+
+        pattern.push('%');
+
+        self.add_condition(
+            field,
+            Operator::LikeEscaped,
+            Logic::And,
+            vec![Box::new(pattern)],
+        )
+    }
+
+    /// Add a where condition matching values that start with the prefix, rendered as a range.
+    ///
+    /// The upper bound increments the last character of the prefix. Characters that cannot be
+    /// incremented are dropped first, and without any upper bound only the lower bound is rendered.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_prefix_range("flight_number", "KL");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE flight_number >= 'KL' AND flight_number < 'KM'"
+    /// );
+    /// ```
+    pub fn where_prefix_range(self, field: &str, prefix: &str) -> Self {
+        let builder = self.add_condition(
+            field,
+            Operator::GreaterThanOrEqual,
+            Logic::And,
+            vec![Box::new(prefix.to_string())],
+        );
+
+        match prefix_upper_bound(prefix) {
+            Some(upper_bound) => builder.add_condition(
+                field,
+                Operator::LessThan,
+                Logic::And,
+                vec![Box::new(upper_bound)],
+            ),
+            None => builder,
+        }
+    }
+
     /// Add a where IN condition to the query.
     ///
     /// ```
@@ -607,130 +904,679 @@ impl QueryBuilder {
         self.add_condition(field, Operator::IsNotNull, Logic::Or, vec![])
     }
 
-    /// Add a where closure condition to the query.
+    /// Add a where condition matching NULL or empty strings to the query.
     ///
     /// ```
     /// use eloquent_core::QueryBuilder;
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_closure(|query| query.r#where("origin", "AMS").or_where("origin", "FRA"));
+    ///     .where_blank("gate");
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE (origin = 'AMS' OR origin = 'FRA')"
+    ///     "SELECT * FROM flights WHERE (gate IS NULL OR gate = '')"
     /// );
     /// ```
-    pub fn where_closure<F>(mut self, closure: F) -> Self
-    where
-        F: FnOnce(Self) -> Self,
-    {
-        let mut nested_builder = QueryBuilder::new();
-
-        nested_builder = closure(nested_builder);
-
-        self.closures.push((Logic::And, nested_builder.conditions));
+    pub fn where_blank(mut self, field: &str) -> Self {
+        self.closures.push((
+            Logic::And,
+            vec![
+                Condition::new(field, Operator::IsNull, Logic::And, vec![]),
+                Condition::new(field, Operator::Equal, Logic::Or, vec![Box::new("")]),
+            ],
+        ));
 
         self
     }
 
-    /// Add an OR where closure condition to the query.
+    /// Add a where condition matching non-NULL and non-empty strings to the query.
     ///
     /// ```
     /// use eloquent_core::QueryBuilder;
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_not_null("departure_time")
-    ///     .or_where_closure(|query| query.r#where("origin", "AMS").r#where("destination", "FRA"));
+    ///     .r#where("origin", "AMS")
+    ///     .where_not_blank("gate");
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE departure_time IS NOT NULL OR (origin = 'AMS' AND destination = 'FRA')"
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' AND (gate IS NOT NULL AND gate != '')"
     /// );
     /// ```
-    pub fn or_where_closure<F>(mut self, closure: F) -> Self
-    where
-        F: FnOnce(Self) -> Self,
-    {
-        let mut nested_builder = QueryBuilder::new();
-
-        nested_builder = closure(nested_builder);
-
-        self.closures.push((Logic::Or, nested_builder.conditions));
+    pub fn where_not_blank(mut self, field: &str) -> Self {
+        self.closures.push((
+            Logic::And,
+            vec![
+                Condition::new(field, Operator::IsNotNull, Logic::And, vec![]),
+                Condition::new(field, Operator::NotEqual, Logic::And, vec![Box::new("")]),
+            ],
+        ));
 
         self
     }
 
-    /// Add a where date condition to the query.
+    /// Add a where exists condition with a subquery to the query.
     ///
     /// ```
-    /// use eloquent_core::QueryBuilder;
+    /// use eloquent_core::{Comparison, QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("id")
+    ///     .where_column("bookings.flight_id", Comparison::Equal, "flights.id");
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_date("departure_date", "2024-10-01");
+    ///     .where_exists(subquery);
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE DATE(departure_date) = '2024-10-01'"
+    ///     "SELECT * FROM flights WHERE EXISTS (SELECT id FROM bookings WHERE bookings.flight_id = flights.id)"
     /// );
     /// ```
-    pub fn where_date(self, field: &str, value: impl ToSql + 'static) -> Self {
-        self.add_condition(field, Operator::Date, Logic::And, vec![Box::new(value)])
+    pub fn where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::And, vec![Box::new(subquery)])
     }
 
-    /// Add a where year condition to the query.
+    /// Add an OR where exists condition with a subquery to the query.
     ///
     /// ```
-    /// use eloquent_core::QueryBuilder;
+    /// use eloquent_core::{Comparison, QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("id")
+    ///     .where_column("bookings.flight_id", Comparison::Equal, "flights.id");
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_year("departure_date", 2024);
+    ///     .r#where("origin", "AMS")
+    ///     .or_where_exists(subquery);
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE YEAR(departure_date) = 2024"
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' OR EXISTS (SELECT id FROM bookings WHERE bookings.flight_id = flights.id)"
     /// );
     /// ```
-    pub fn where_year(self, field: &str, value: impl ToSql + 'static) -> Self {
-        self.add_condition(field, Operator::Year, Logic::And, vec![Box::new(value)])
+    pub fn or_where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::Or, vec![Box::new(subquery)])
     }
 
-    /// Add a where month condition to the query.
+    /// Add a where not exists condition with a subquery to the query.
     ///
     /// ```
-    /// use eloquent_core::QueryBuilder;
+    /// use eloquent_core::{Comparison, QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("id")
+    ///     .where_column("bookings.flight_id", Comparison::Equal, "flights.id");
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_month("departure_date", 10);
+    ///     .where_not_exists(subquery);
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE MONTH(departure_date) = 10"
+    ///     "SELECT * FROM flights WHERE NOT EXISTS (SELECT id FROM bookings WHERE bookings.flight_id = flights.id)"
     /// );
     /// ```
-    pub fn where_month(self, field: &str, value: impl ToSql + 'static) -> Self {
-        self.add_condition(field, Operator::Month, Logic::And, vec![Box::new(value)])
+    pub fn where_not_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition(
+            "",
+            Operator::NotExists,
+            Logic::And,
+            vec![Box::new(subquery)],
+        )
     }
 
-    /// Add a where day condition to the query.
+    /// Add a raw where condition to the query.
     ///
     /// ```
     /// use eloquent_core::QueryBuilder;
     ///
     /// let result = QueryBuilder::new()
     ///     .table("flights")
-    ///     .where_day("departure_date", 10);
+    ///     .where_raw("DATE(created_at) = ?", vec!["2024-01-01"]);
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM flights WHERE DAY(departure_date) = 10"
+    ///     "SELECT * FROM flights WHERE DATE(created_at) = '2024-01-01'"
     /// );
     /// ```
-    pub fn where_day(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_raw(self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(raw, Operator::Raw, Logic::And, values)
+    }
+
+    /// Add a raw OR where condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .or_where_raw("duration_in_min BETWEEN ? AND ?", vec![60, 120]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' OR duration_in_min BETWEEN 60 AND 120"
+    /// );
+    /// ```
+    pub fn or_where_raw(self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(raw, Operator::Raw, Logic::Or, values)
+    }
+
+    /// Add a where closure condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_closure(|query| query.r#where("origin", "AMS").or_where("origin", "FRA"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE (origin = 'AMS' OR origin = 'FRA')"
+    /// );
+    /// ```
+    pub fn where_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let mut nested_builder = QueryBuilder::new();
+
+        nested_builder = closure(nested_builder);
+
+        self.closures.push((Logic::And, nested_builder.conditions));
+
+        self
+    }
+
+    /// Add an OR where closure condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_not_null("departure_time")
+    ///     .or_where_closure(|query| query.r#where("origin", "AMS").r#where("destination", "FRA"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departure_time IS NOT NULL OR (origin = 'AMS' AND destination = 'FRA')"
+    /// );
+    /// ```
+    pub fn or_where_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let mut nested_builder = QueryBuilder::new();
+
+        nested_builder = closure(nested_builder);
+
+        self.closures.push((Logic::Or, nested_builder.conditions));
+
+        self
+    }
+
+    /// Add a where date condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_date("departure_date", "2024-10-01");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE DATE(departure_date) = '2024-10-01'"
+    /// );
+    /// ```
+    pub fn where_date(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::Date, Logic::And, vec![Box::new(value)])
+    }
+
+    /// Add a where year condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_year("departure_date", 2024);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE YEAR(departure_date) = 2024"
+    /// );
+    /// ```
+    pub fn where_year(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::Year, Logic::And, vec![Box::new(value)])
+    }
+
+    /// Add a where month condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_month("departure_date", 10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE MONTH(departure_date) = 10"
+    /// );
+    /// ```
+    pub fn where_month(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::Month, Logic::And, vec![Box::new(value)])
+    }
+
+    /// Add a where day condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_day("departure_date", 10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE DAY(departure_date) = 10"
+    /// );
+    /// ```
+    pub fn where_day(self, field: &str, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Day, Logic::And, vec![Box::new(value)])
     }
+
+    /// Add a where condition matching rows within the last interval from now.
+    ///
+    /// ```
+    /// use eloquent_core::{IntervalUnit, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_within_last("departed_at", 7, IntervalUnit::Days);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departed_at >= DATE_SUB(NOW(), INTERVAL 7 DAY)"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, IntervalUnit, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_within_last("departed_at", 7, IntervalUnit::Days);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departed_at >= NOW() - INTERVAL '7 days'"
+    /// );
+    /// ```
+    pub fn where_within_last(self, field: &str, amount: u32, unit: IntervalUnit) -> Self {
+        self.add_condition(
+            field,
+            Operator::WithinLast(amount, unit),
+            Logic::And,
+            vec![],
+        )
+    }
+
+    /// Add a where condition matching rows that have all bits of a flag set.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("users")
+    ///     .where_has_flag("permissions", 4);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM users WHERE (permissions & 4) = 4"
+    /// );
+    /// ```
+    pub fn where_has_flag(self, field: &str, flag: u64) -> Self {
+        self.add_condition(
+            field,
+            Operator::HasFlag,
+            Logic::And,
+            vec![Box::new(flag), Box::new(flag)],
+        )
+    }
+
+    /// Add a where condition matching arrays that share any element with the given values, Postgres only.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_array_overlaps("tags", vec!["red-eye", "pilot's choice"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE tags && ARRAY['red-eye', 'pilot''s choice']"
+    /// );
+    /// ```
+    pub fn where_array_overlaps(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let boxed_values = values
+            .into_iter()
+            .map(|v| Box::new(v) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(field, Operator::ArrayOverlaps, Logic::And, boxed_values)
+    }
+
+    /// Add a where condition matching arrays that contain all of the given values, Postgres only.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_array_contains("tags", vec!["red-eye", "direct"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE tags @> ARRAY['red-eye', 'direct']"
+    /// );
+    /// ```
+    pub fn where_array_contains(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let boxed_values = values
+            .into_iter()
+            .map(|v| Box::new(v) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(field, Operator::ArrayContains, Logic::And, boxed_values)
+    }
+
+    /// Add a where condition matching rows whose time of day is between two times.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_time_between("departure_time", "08:00", "12:00");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE TIME(departure_time) BETWEEN '08:00' AND '12:00'"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_time_between("departure_time", "08:00", "12:00");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE CAST(departure_time AS TIME) BETWEEN '08:00' AND '12:00'"
+    /// );
+    /// ```
+    pub fn where_time_between(
+        self,
+        field: &str,
+        start: impl ToSql + 'static,
+        end: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::TimeBetween,
+            Logic::And,
+            vec![Box::new(start), Box::new(end)],
+        )
+    }
+
+    /// Add a where condition compared using an explicit collation.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("users")
+    ///     .where_collate("name", Comparison::Equal, "José", "utf8mb4_bin");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM users WHERE name = 'José' COLLATE utf8mb4_bin"
+    /// );
+    /// ```
+    pub fn where_collate(
+        mut self,
+        field: &str,
+        operator: Comparison,
+        value: impl ToSql + 'static,
+        collation: &str,
+    ) -> Self {
+        let mut condition =
+            Condition::new(field, operator.into(), Logic::And, vec![Box::new(value)]);
+        condition.collation = Some(collation.to_string());
+
+        self.conditions.push(condition);
+
+        self
+    }
+
+    /// Add a where condition matching rows whose period overlaps the given period.
+    ///
+    /// The `OVERLAPS` predicate is only supported by Postgres and the ANSI dialect.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("bookings")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_overlaps("starts_on", "ends_on", "2024-07-01", "2024-07-14");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM bookings WHERE (starts_on, ends_on) OVERLAPS ('2024-07-01', '2024-07-14')"
+    /// );
+    /// ```
+    pub fn where_overlaps(
+        self,
+        start: &str,
+        end: &str,
+        other_start: impl ToSql + 'static,
+        other_end: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            &format!("{}, {}", start, end),
+            Operator::Overlaps,
+            Logic::And,
+            vec![Box::new(other_start), Box::new(other_end)],
+        )
+    }
+
+    /// Add a where condition matching rows whose period overlaps the period of two other columns.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("bookings")
+    ///     .dialect(Dialect::Postgres)
+    ///     .join("maintenance", "maintenance.room_id", "bookings.room_id")
+    ///     .where_overlaps_columns(
+    ///         "bookings.starts_on",
+    ///         "bookings.ends_on",
+    ///         "maintenance.starts_on",
+    ///         "maintenance.ends_on",
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM bookings JOIN maintenance ON maintenance.room_id = bookings.room_id WHERE (bookings.starts_on, bookings.ends_on) OVERLAPS (maintenance.starts_on, maintenance.ends_on)"
+    /// );
+    /// ```
+    pub fn where_overlaps_columns(
+        self,
+        start: &str,
+        end: &str,
+        other_start: &str,
+        other_end: &str,
+    ) -> Self {
+        self.add_condition(
+            &format!("{}, {}", start, end),
+            Operator::Overlaps,
+            Logic::And,
+            vec![
+                Box::new(Column(other_start.to_string())),
+                Box::new(Column(other_end.to_string())),
+            ],
+        )
+    }
+
+    /// Add conditions built outside of the query, each joined by its own `AND` or `OR`.
+    ///
+    /// ```
+    /// use eloquent_core::{Condition, Comparison, QueryBuilder};
+    ///
+    /// let conditions = vec![
+    ///     Condition::and("origin", Comparison::Equal, "AMS"),
+    ///     Condition::or("destination", Comparison::Equal, "AMS"),
+    /// ];
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .add_conditions(conditions);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' OR destination = 'AMS'"
+    /// );
+    /// ```
+    pub fn add_conditions(self, conditions: Vec<Condition>) -> Self {
+        conditions.into_iter().fold(self, |query, condition| {
+            query.add_condition(
+                &condition.field,
+                condition.operator,
+                condition.logic,
+                condition.values,
+            )
+        })
+    }
+
+    /// Add a where condition matching rows where a comma-separated column contains the value.
+    ///
+    /// MySQL uses `FIND_IN_SET`, other dialects search the comma-wrapped column for the comma-wrapped value.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_in_set("amenities", "wifi");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE FIND_IN_SET('wifi', amenities) > 0"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_in_set("amenities", "wifi");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE POSITION(',' || 'wifi' || ',' IN ',' || amenities || ',') > 0"
+    /// );
+    /// ```
+    pub fn where_in_set(self, field: &str, value: &str) -> Self {
+        self.add_condition(
+            field,
+            Operator::InSet,
+            Logic::And,
+            vec![Box::new(value.to_string())],
+        )
+    }
+
+    /// Add a where condition matching geometries within a distance in meters of a point.
+    ///
+    /// Postgres renders the PostGIS `ST_DWithin` on geography and MySQL uses `ST_Distance_Sphere`,
+    /// other dialects are not supported. Coordinates are WGS 84 longitude and latitude.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("airports")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_within_distance("location", 4.76, 52.31, 5000.0);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM airports WHERE ST_DWithin(location::geography, ST_SetSRID(ST_MakePoint(4.76, 52.31), 4326)::geography, 5000.0)"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("airports")
+    ///     .where_within_distance("location", 4.76, 52.31, 5000.0);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM airports WHERE ST_Distance_Sphere(location, POINT(4.76, 52.31)) <= 5000.0"
+    /// );
+    /// ```
+    pub fn where_within_distance(
+        self,
+        field: &str,
+        longitude: f64,
+        latitude: f64,
+        meters: f64,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::WithinDistance,
+            Logic::And,
+            vec![Box::new(longitude), Box::new(latitude), Box::new(meters)],
+        )
+    }
+}
+
+/// The smallest string greater than every string starting with the prefix, if there is one.
+fn prefix_upper_bound(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+
+    while let Some(last) = chars.pop() {
+        // skips the surrogate range, and char::MAX has no successor
+        if let Some(next) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            chars.push(next);
+
+            return Some(chars.into_iter().collect());
+        }
+    }
+
+    None
 }
@@ -0,0 +1,105 @@
+use crate::{Cte, QueryBuilder, SubqueryBuilder};
+
+impl QueryBuilder {
+    /// Add a named subquery that can be used as a table in the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let delayed = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .where_gt("delay_minutes", 30);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .with("delayed", delayed)
+    ///     .table("delayed")
+    ///     .select("flight_number");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "WITH delayed AS (SELECT flight_number FROM flights WHERE delay_minutes > 30) SELECT flight_number FROM delayed"
+    /// );
+    /// ```
+    pub fn with(mut self, name: &str, query: SubqueryBuilder) -> Self {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            queries: vec![query],
+            recursive: false,
+        });
+
+        self
+    }
+
+    /// Add a named subquery that may refer to itself.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let routes = SubqueryBuilder::new()
+    ///     .table("connections")
+    ///     .select(vec!["origin", "destination"]);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .with_recursive("routes", routes)
+    ///     .table("routes")
+    ///     .select("destination")
+    ///     .r#where("origin", "AMS");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "WITH RECURSIVE routes AS (SELECT origin, destination FROM connections) SELECT destination FROM routes WHERE origin = 'AMS'"
+    /// );
+    /// ```
+    pub fn with_recursive(mut self, name: &str, query: SubqueryBuilder) -> Self {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            queries: vec![query],
+            recursive: true,
+        });
+
+        self
+    }
+
+    /// Add a recursive named subquery, built from an anchor query and a recursive query combined with `UNION ALL`.
+    ///
+    /// The recursive query refers to the name of the subquery to walk one level further.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let anchor = SubqueryBuilder::new()
+    ///     .table("employees")
+    ///     .select(vec!["id", "manager_id"])
+    ///     .where_null("manager_id");
+    ///
+    /// let recursive = SubqueryBuilder::new()
+    ///     .table("employees")
+    ///     .join("tree", "tree.id", "employees.manager_id")
+    ///     .select(vec!["employees.id", "employees.manager_id"]);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .with_recursive_union("tree", anchor, recursive)
+    ///     .table("tree")
+    ///     .select("id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "WITH RECURSIVE tree AS (SELECT id, manager_id FROM employees WHERE manager_id IS NULL UNION ALL SELECT employees.id, employees.manager_id FROM employees JOIN tree ON tree.id = employees.manager_id) SELECT id FROM tree"
+    /// );
+    /// ```
+    pub fn with_recursive_union(
+        mut self,
+        name: &str,
+        anchor: SubqueryBuilder,
+        recursive: SubqueryBuilder,
+    ) -> Self {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            queries: vec![anchor, recursive],
+            recursive: true,
+        });
+
+        self
+    }
+}
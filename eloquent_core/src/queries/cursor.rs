@@ -0,0 +1,109 @@
+use crate::{Condition, Logic, Operator, Order, OrderColumn, OrderKind, QueryBuilder, ToSql};
+
+impl QueryBuilder {
+    /// Fetch the rows after a cursor value, ordered by the cursor column.
+    ///
+    /// Calling it again replaces the previous cursor and its ordering.
+    ///
+    /// ```
+    /// use eloquent_core::{Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .after_cursor("id", 1000, Order::Asc)
+    ///     .limit(25);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE id > 1000 ORDER BY id ASC LIMIT 25"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .after_cursor("id", 1000, Order::Desc)
+    ///     .limit(25);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' AND id < 1000 ORDER BY id DESC LIMIT 25"
+    /// );
+    /// ```
+    pub fn after_cursor(self, column: &str, value: impl ToSql + 'static, order: Order) -> Self {
+        self.after_cursor_multi(vec![(column, Box::new(value))], order)
+    }
+
+    /// Fetch the rows after a composite cursor, ordered by all cursor columns.
+    ///
+    /// ```
+    /// use eloquent_core::{Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .after_cursor_multi(
+    ///         vec![
+    ///             ("departure_time", Box::new("2024-01-01 10:00:00")),
+    ///             ("id", Box::new(1000)),
+    ///         ],
+    ///         Order::Asc,
+    ///     )
+    ///     .limit(25);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE (departure_time, id) > ('2024-01-01 10:00:00', 1000) ORDER BY departure_time ASC, id ASC LIMIT 25"
+    /// );
+    /// ```
+    pub fn after_cursor_multi(mut self, cursor: Vec<(&str, Box<dyn ToSql>)>, order: Order) -> Self {
+        if cursor.is_empty() {
+            return self;
+        }
+
+        let operator = match order {
+            Order::Asc => Operator::GreaterThan,
+            Order::Desc => Operator::LessThan,
+        };
+
+        let columns: Vec<&str> = cursor.iter().map(|(column, _)| *column).collect();
+
+        let field = match columns.as_slice() {
+            [column] => column.to_string(),
+            _ => format!("({})", columns.join(", ")),
+        };
+
+        // a new cursor replaces the ordering added by the previous one
+        if let Some(previous) = &self.cursor {
+            let previous: Vec<&str> = previous
+                .field
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .split(", ")
+                .collect();
+
+            self.order_by.retain(|order| {
+                !matches!(order.kind, OrderKind::Column)
+                    || !previous.contains(&order.column.as_str())
+            });
+        }
+
+        for column in &columns {
+            self.order_by.push(OrderColumn {
+                column: column.to_string(),
+                order,
+                nulls: None,
+                kind: OrderKind::Column,
+                values: Vec::new(),
+            });
+        }
+
+        let values = cursor.into_iter().map(|(_, value)| value).collect();
+
+        self.cursor = Some(Condition::new(&field, operator, Logic::And, values));
+
+        self
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{ExplainOptions, QueryBuilder};
+
+impl QueryBuilder {
+    /// Show the execution plan of the query instead of running it.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .explain();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "EXPLAIN SELECT * FROM flights WHERE origin = 'AMS'"
+    /// );
+    /// ```
+    pub fn explain(mut self) -> Self {
+        self.explain = Some(ExplainOptions::default());
+
+        self
+    }
+
+    /// Show the execution plan of the query with Postgres options.
+    ///
+    /// `BUFFERS` requires `ANALYZE`, since buffer usage is only measured while the query runs.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, ExplainFormat, ExplainOptions, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .explain_options(ExplainOptions {
+    ///         analyze: true,
+    ///         buffers: true,
+    ///         format: Some(ExplainFormat::Json),
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT * FROM flights WHERE origin = 'AMS'"
+    /// );
+    /// ```
+    pub fn explain_options(mut self, options: ExplainOptions) -> Self {
+        self.explain = Some(options);
+
+        self
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Column, FromFunction, QueryBuilder, ToSql};
+
+impl QueryBuilder {
+    /// Select from a table function called with bound arguments.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .from_function("unnest", vec![Box::new("{1,2,3}")], "x")
+    ///     .select("x");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT x FROM unnest('{1,2,3}') AS x"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .from_function("generate_series", vec![Box::new(1), Box::new(10)], "n")
+    ///     .where_gt("n", 5);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM generate_series(1, 10) AS n WHERE n > 5"
+    /// );
+    /// ```
+    ///
+    /// Arguments wrapped in [`Cast`](crate::Cast) keep their type when bound.
+    ///
+    /// ```
+    /// use eloquent_core::{Cast, Dialect, QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .from_function("unnest", vec![Box::new(Cast("{1,2,3}", "int[]"))], "x")
+    ///     .select("x")
+    ///     .sql_with_bindings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sql, "SELECT x FROM unnest($1::int[]) AS x");
+    /// assert_eq!(bindings, vec![Variable::String("{1,2,3}".to_string())]);
+    /// ```
+    pub fn from_function(mut self, name: &str, args: Vec<Box<dyn ToSql>>, alias: &str) -> Self {
+        self.table = Some(alias.to_string());
+        self.from_function = Some(FromFunction {
+            name: name.to_string(),
+            args,
+            alias: alias.to_string(),
+            columns: Vec::new(),
+            with_ordinality: false,
+        });
+
+        self
+    }
+
+    /// Select the elements of an array together with their position (Postgres).
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .from_unnest_ordinality("ARRAY['AMS', 'FRA', 'LHR']", "stops", "airport", "position")
+    ///     .select(vec!["airport", "position"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT airport, position FROM unnest(ARRAY['AMS', 'FRA', 'LHR']) WITH ORDINALITY AS stops(airport, position)"
+    /// );
+    /// ```
+    pub fn from_unnest_ordinality(
+        mut self,
+        array_expression: &str,
+        alias: &str,
+        value_column: &str,
+        index_column: &str,
+    ) -> Self {
+        self.table = Some(alias.to_string());
+        self.from_function = Some(FromFunction {
+            name: "unnest".to_string(),
+            args: vec![Box::new(Column(array_expression.to_string()))],
+            alias: alias.to_string(),
+            columns: vec![value_column.to_string(), index_column.to_string()],
+            with_ordinality: true,
+        });
+
+        self
+    }
+}
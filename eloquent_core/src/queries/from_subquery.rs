@@ -0,0 +1,35 @@
+use crate::{FromSubquery, QueryBuilder, SubqueryBuilder};
+
+impl QueryBuilder {
+    /// Select from a derived table instead of a table, referring to it by the alias.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let delayed = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flight_number", "origin"])
+    ///     .where_gt("delay_minutes", 30);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .from_sub(delayed, "delayed")
+    ///     .join("airports", "airports.code", "delayed.origin")
+    ///     .select(vec!["delayed.flight_number", "airports.name"])
+    ///     .r#where("airports.country", "NL");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT delayed.flight_number, airports.name FROM (SELECT flight_number, origin FROM flights WHERE delay_minutes > 30) AS delayed JOIN airports ON airports.code = delayed.origin WHERE airports.country = 'NL'"
+    /// );
+    /// ```
+    pub fn from_sub(mut self, subquery: SubqueryBuilder, alias: &str) -> Self {
+        // an existing table is kept so the conflict is reported when compiling
+        self.table.get_or_insert_with(|| alias.to_string());
+        self.from_subquery = Some(FromSubquery {
+            query: Box::new(subquery),
+            alias: alias.to_string(),
+        });
+
+        self
+    }
+}
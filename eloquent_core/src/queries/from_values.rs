@@ -0,0 +1,80 @@
+use crate::{FromValues, QueryBuilder, ToSql};
+
+impl QueryBuilder {
+    /// Select from a derived table of literal rows with named columns.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .from_values(
+    ///         vec![
+    ///             vec![Box::new(1), Box::new("AMS")],
+    ///             vec![Box::new(2), Box::new("FRA")],
+    ///         ],
+    ///         "airports",
+    ///         vec!["id", "code"],
+    ///     )
+    ///     .select("code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT code FROM (VALUES ROW(1, 'AMS'), ROW(2, 'FRA')) AS airports(id, code)"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .from_values(
+    ///         vec![
+    ///             vec![Box::new(1), Box::new("AMS")],
+    ///             vec![Box::new(2), Box::new("FRA")],
+    ///         ],
+    ///         "airports",
+    ///         vec!["id", "code"],
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM (VALUES (1, 'AMS'), (2, 'FRA')) AS airports(id, code)"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Sqlite)
+    ///     .from_values(
+    ///         vec![
+    ///             vec![Box::new(1), Box::new("AMS")],
+    ///             vec![Box::new(2), Box::new("FRA")],
+    ///         ],
+    ///         "airports",
+    ///         vec!["id", "code"],
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM (SELECT 1 AS id, 'AMS' AS code UNION ALL SELECT 2, 'FRA') AS airports"
+    /// );
+    /// ```
+    pub fn from_values(
+        mut self,
+        rows: Vec<Vec<Box<dyn ToSql>>>,
+        alias: &str,
+        columns: Vec<&str>,
+    ) -> Self {
+        self.table = Some(alias.to_string());
+        self.from_values = Some(FromValues {
+            rows,
+            alias: alias.to_string(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+        });
+
+        self
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Columnable, QueryBuilder};
+use crate::{
+    checks::having_clause_without_aggregate_function::AGGREGATE_FUNCTIONS, Columnable,
+    QueryBuilder, Select,
+};
 
 impl QueryBuilder {
     /// Add a group by clause to the query.
@@ -44,4 +47,180 @@ impl QueryBuilder {
 
         self
     }
+
+    /// Add a raw expression to the group by clause, in order with the other group by columns.
+    ///
+    /// The expression is not validated against the selected columns.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_count("id", "flight_count")
+    ///     .group_by("origin")
+    ///     .group_by_raw("YEAR(departed_at)");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, COUNT(id) AS flight_count FROM flights GROUP BY origin, YEAR(departed_at)"
+    /// );
+    /// ```
+    pub fn group_by_raw(mut self, expression: &str) -> Self {
+        self.group_by.push(expression.to_string());
+        self.raw_group_by.push(expression.to_string());
+
+        self
+    }
+
+    /// Add subtotal rows for every prefix of the group by columns, and a grand total row.
+    ///
+    /// MySQL appends `WITH ROLLUP`, other dialects wrap the columns in `ROLLUP(...)`.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["origin", "destination"])
+    ///     .select_count("id", "flight_count")
+    ///     .group_by(vec!["origin", "destination"])
+    ///     .with_rollup();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, destination, COUNT(id) AS flight_count FROM flights GROUP BY origin, destination WITH ROLLUP"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .select(vec!["origin", "destination"])
+    ///     .select_count("id", "flight_count")
+    ///     .group_by(vec!["origin", "destination"])
+    ///     .with_rollup();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, destination, COUNT(id) AS flight_count FROM flights GROUP BY ROLLUP(origin, destination)"
+    /// );
+    /// ```
+    pub fn with_rollup(mut self) -> Self {
+        self.rollup = true;
+
+        self
+    }
+
+    /// Group by every selected column that is not aggregated when the query contains aggregates.
+    ///
+    /// Raw and aliased expressions are grouped by as well, unless they contain an aggregate or window
+    /// function. Expressions with bindings cannot be repeated in the GROUP BY and are an error.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["origin", "destination"])
+    ///     .select_avg("flight_duration", "flight_duration_avg")
+    ///     .auto_group_by();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, destination, AVG(flight_duration) AS flight_duration_avg FROM flights GROUP BY origin, destination"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["origin", "destination"])
+    ///     .auto_group_by();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, destination FROM flights"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_raw("YEAR(departed_at) AS departure_year", Vec::<i32>::new())
+    ///     .select_count("id", "flight_count")
+    ///     .auto_group_by();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT YEAR(departed_at) AS departure_year, COUNT(id) AS flight_count FROM flights GROUP BY YEAR(departed_at)"
+    /// );
+    /// ```
+    pub fn auto_group_by(mut self) -> Self {
+        self.auto_group_by = true;
+
+        self
+    }
+
+    pub(crate) fn group_by_columns(&self) -> Vec<String> {
+        let mut group_by = self.group_by.clone();
+
+        for select in self.auto_grouped_selects() {
+            let column = without_alias(&select.column).to_string();
+
+            if !group_by.contains(&column) {
+                group_by.push(column);
+            }
+        }
+
+        group_by
+    }
+
+    /// The selected columns and expressions that `auto_group_by()` adds to the GROUP BY.
+    pub(crate) fn auto_grouped_selects(&self) -> Vec<&Select> {
+        if !self.auto_group_by || !self.selects.iter().any(|select| select.is_aggregate()) {
+            return Vec::new();
+        }
+
+        self.selects
+            .iter()
+            .filter(|select| {
+                select.function.is_none()
+                    && select.window.is_none()
+                    && !select.is_null_placeholder()
+                    && is_groupable(&select.column)
+            })
+            .collect()
+    }
+}
+
+/// Wildcards, aggregates and window functions can not be grouped by.
+fn is_groupable(column: &str) -> bool {
+    let uppercased = column.to_ascii_uppercase();
+
+    column != "*"
+        && !column.ends_with(".*")
+        && !uppercased.contains(" OVER ")
+        && !uppercased.contains(" OVER(")
+        && !AGGREGATE_FUNCTIONS
+            .iter()
+            .any(|function| uppercased.contains(function))
+}
+
+/// A raw select may name its expression, which is left out of the GROUP BY.
+fn without_alias(column: &str) -> &str {
+    match column.to_ascii_uppercase().rfind(" AS ") {
+        Some(index)
+            if column[index + 4..]
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_') =>
+        {
+            &column[..index]
+        }
+        _ => column,
+    }
 }
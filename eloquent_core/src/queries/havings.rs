@@ -1,4 +1,4 @@
-use crate::{Condition, Having, Logic, Operator, QueryBuilder};
+use crate::{Column, Comparison, Condition, Having, Logic, Operator, QueryBuilder, ToSql};
 
 impl QueryBuilder {
     /// Add a having clause to the query.
@@ -158,22 +158,165 @@ impl QueryBuilder {
                 operator: Operator::Between,
                 logic: Logic::And,
                 values: vec![Box::new(value_1), Box::new(value_2)],
+                collation: None,
             }],
+            compare_columns: false,
         });
 
         self
     }
 
-    fn add_having(mut self, column: &str, value: i64, operator: Operator) -> Self {
+    /// Add a having clause comparing two aggregate expressions to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .group_by("origin_airport")
+    ///     .having_column("SUM(delay_in_min)", Comparison::GreaterThan, "SUM(flight_duration)");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin_airport FROM flights GROUP BY origin_airport HAVING SUM(delay_in_min) > SUM(flight_duration)"
+    /// );
+    /// ```
+    pub fn having_column(
+        mut self,
+        left_hand: &str,
+        operator: Comparison,
+        right_hand: &str,
+    ) -> Self {
+        self.havings.push(Having {
+            conditions: vec![Condition {
+                field: left_hand.to_string(),
+                operator: operator.into(),
+                logic: Logic::And,
+                values: vec![Box::new(Column(right_hand.to_string()))],
+                collation: None,
+            }],
+            compare_columns: true,
+        });
+
+        self
+    }
+
+    /// Add an alternative having clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .select_count("id", "flight_count")
+    ///     .group_by("origin_airport")
+    ///     .having_gt("flight_count", 100)
+    ///     .or_having("flight_count", 0);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin_airport, COUNT(id) AS flight_count FROM flights GROUP BY origin_airport HAVING flight_count > 100 OR flight_count = 0"
+    /// );
+    /// ```
+    pub fn or_having(self, column: &str, value: i64) -> Self {
+        self.push_having(column, Operator::Equal, Logic::Or, vec![Box::new(value)])
+    }
+
+    /// Add a having in clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .select_count("id", "flight_count")
+    ///     .group_by("origin_airport")
+    ///     .having_in("flight_count", vec![1, 2, 3]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin_airport, COUNT(id) AS flight_count FROM flights GROUP BY origin_airport HAVING flight_count IN (1, 2, 3)"
+    /// );
+    /// ```
+    pub fn having_in(self, column: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        self.push_having(column, Operator::In, Logic::And, boxed(values))
+    }
+
+    /// Add a having not in clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .select_count("id", "flight_count")
+    ///     .group_by("origin_airport")
+    ///     .having_not_in("flight_count", vec![1, 2, 3]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin_airport, COUNT(id) AS flight_count FROM flights GROUP BY origin_airport HAVING flight_count NOT IN (1, 2, 3)"
+    /// );
+    /// ```
+    pub fn having_not_in(self, column: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        self.push_having(column, Operator::NotIn, Logic::And, boxed(values))
+    }
+
+    /// Add a raw having clause to the query, with `?` placeholders for the values.
+    ///
+    /// The expression is not validated against the selected aggregates.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .group_by("origin_airport")
+    ///     .having_raw("COUNT(*) > ?", vec![5]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin_airport FROM flights GROUP BY origin_airport HAVING COUNT(*) > 5"
+    /// );
+    /// ```
+    pub fn having_raw(self, expression: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        self.push_having(expression, Operator::Raw, Logic::And, boxed(values))
+    }
+
+    fn add_having(self, column: &str, value: i64, operator: Operator) -> Self {
+        self.push_having(column, operator, Logic::And, vec![Box::new(value)])
+    }
+
+    fn push_having(
+        mut self,
+        column: &str,
+        operator: Operator,
+        logic: Logic,
+        values: Vec<Box<dyn ToSql>>,
+    ) -> Self {
         self.havings.push(Having {
             conditions: vec![Condition {
                 field: column.to_string(),
                 operator,
-                logic: Logic::And,
-                values: vec![Box::new(value)],
+                logic,
+                values,
+                collation: None,
             }],
+            compare_columns: false,
         });
 
         self
     }
 }
+
+fn boxed(values: Vec<impl ToSql + 'static>) -> Vec<Box<dyn ToSql>> {
+    values
+        .into_iter()
+        .map(|value| Box::new(value) as Box<dyn ToSql>)
+        .collect()
+}
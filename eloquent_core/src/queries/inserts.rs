@@ -1,4 +1,4 @@
-use crate::{Insert, QueryBuilder, ToSql};
+use crate::{Assignment, Columnable, Insert, QueryBuilder, ToSql, Upsert};
 
 impl QueryBuilder {
     /// Insert single or multiple columns into the table.
@@ -24,4 +24,277 @@ impl QueryBuilder {
 
         self
     }
+
+    /// Insert multiple rows of columns and values into the table.
+    ///
+    /// The columns are ordered by the first row, and every other row must have the same columns.
+    /// Passing no rows, or a row without columns, is an error.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, ToSql};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert_many(vec![
+    ///         vec![
+    ///             ("origin_airport", Box::new("AMS") as Box<dyn ToSql>),
+    ///             ("flight_duration", Box::new(90)),
+    ///         ],
+    ///         vec![
+    ///             ("flight_duration", Box::new(120) as Box<dyn ToSql>),
+    ///             ("origin_airport", Box::new("FRA")),
+    ///         ],
+    ///     ]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (origin_airport, flight_duration) VALUES ('AMS', 90), ('FRA', 120)"
+    /// );
+    /// ```
+    pub fn insert_many(mut self, rows: Vec<Vec<(&str, Box<dyn ToSql>)>>) -> Self {
+        if rows.is_empty() || rows.iter().any(Vec::is_empty) {
+            self.empty_insert = true;
+        }
+
+        for row in rows {
+            let row = row
+                .into_iter()
+                .map(|(column, value)| Insert {
+                    column: column.to_string(),
+                    value,
+                })
+                .collect();
+
+            if self.inserts.is_empty() {
+                self.inserts = row;
+            } else {
+                self.insert_rows.push(row);
+            }
+        }
+
+        self
+    }
+
+    /// Insert a row, or update the given columns when it conflicts with an existing row.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, ToSql};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .upsert(
+    ///         vec![
+    ///             ("flight_number", Box::new("KL123") as Box<dyn ToSql>),
+    ///             ("status", Box::new("boarding")),
+    ///         ],
+    ///         vec!["flight_number"],
+    ///         vec!["status"],
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'boarding') ON DUPLICATE KEY UPDATE status = VALUES(status)"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder, ToSql};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .upsert(
+    ///         vec![
+    ///             ("flight_number", Box::new("KL123") as Box<dyn ToSql>),
+    ///             ("status", Box::new("boarding")),
+    ///         ],
+    ///         vec!["flight_number"],
+    ///         vec!["status"],
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'boarding') ON CONFLICT (flight_number) DO UPDATE SET status = EXCLUDED.status"
+    /// );
+    /// ```
+    pub fn upsert(
+        self,
+        values: Vec<(&str, Box<dyn ToSql>)>,
+        conflict_columns: Vec<&str>,
+        update_columns: Vec<&str>,
+    ) -> Self {
+        let mut builder = self.insert_many(vec![values]);
+
+        builder.upsert = Some(Upsert {
+            conflict_columns: conflict_columns.iter().map(|c| c.to_string()).collect(),
+            update_columns: update_columns.iter().map(|c| c.to_string()).collect(),
+        });
+
+        builder
+    }
+
+    /// Update a column when the insert hits a duplicate key (MySQL).
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Values};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("status", "boarding")
+    ///     .on_duplicate_key_update("status", Values("status"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'boarding') ON DUPLICATE KEY UPDATE status = VALUES(status)"
+    /// );
+    /// ```
+    pub fn on_duplicate_key_update(mut self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.on_duplicate_key_updates.push(Assignment {
+            column: column.to_string(),
+            expression: "?".to_string(),
+            values: vec![Box::new(value)],
+        });
+
+        self
+    }
+
+    /// Update a column with a raw SQL expression when the insert hits a duplicate key (MySQL).
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Values};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("orders")
+    ///     .insert("customer_id", 1)
+    ///     .insert("total", 250)
+    ///     .on_duplicate_key_update_raw("total", "total + ?", vec![Values("total")]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO orders (customer_id, total) VALUES (1, 250) ON DUPLICATE KEY UPDATE total = total + VALUES(total)"
+    /// );
+    /// ```
+    pub fn on_duplicate_key_update_raw(
+        mut self,
+        column: &str,
+        raw: &str,
+        values: Vec<impl ToSql + 'static>,
+    ) -> Self {
+        self.on_duplicate_key_updates.push(Assignment {
+            column: column.to_string(),
+            expression: raw.to_string(),
+            values: values
+                .into_iter()
+                .map(|v| Box::new(v) as Box<dyn ToSql>)
+                .collect(),
+        });
+
+        self
+    }
+
+    /// Turn the duplicate key updates into an `ON CONFLICT ... DO UPDATE` clause on the given columns (Postgres, SQLite).
+    ///
+    /// Without any duplicate key updates, conflicting rows are skipped with `DO NOTHING`.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, Excluded, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("status", "boarding")
+    ///     .on_conflict(vec!["flight_number"])
+    ///     .on_duplicate_key_update("status", Excluded("status"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'boarding') ON CONFLICT (flight_number) DO UPDATE SET status = EXCLUDED.status"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Sqlite)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .on_conflict(vec!["flight_number"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number) VALUES ('KL123') ON CONFLICT (flight_number) DO NOTHING"
+    /// );
+    /// ```
+    pub fn on_conflict(mut self, columns: Vec<&str>) -> Self {
+        self.conflict_target = Some(columns.iter().map(|column| column.to_string()).collect());
+
+        self
+    }
+
+    /// Return whether the upsert inserted a new row, using the `xmax` system column (Postgres).
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, Excluded, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("status", "boarding")
+    ///     .on_conflict(vec!["flight_number"])
+    ///     .on_duplicate_key_update("status", Excluded("status"))
+    ///     .returning_inserted_flag("inserted");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'boarding') ON CONFLICT (flight_number) DO UPDATE SET status = EXCLUDED.status RETURNING (xmax = 0) AS inserted"
+    /// );
+    /// ```
+    pub fn returning_inserted_flag(mut self, alias: &str) -> Self {
+        self.inserted_flag = Some(alias.to_string());
+
+        self
+    }
+
+    /// Return the given columns of the inserted rows.
+    ///
+    /// MySQL has no `RETURNING`, so with `multiple_statements()` a single auto increment column is
+    /// returned by a trailing `SELECT LAST_INSERT_ID()` statement.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .returning(vec!["id", "created_at"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number) VALUES ('KL123') RETURNING id, created_at"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .returning("id")
+    ///     .multiple_statements();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number) VALUES ('KL123'); SELECT LAST_INSERT_ID() AS id"
+    /// );
+    /// ```
+    pub fn returning<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.returning.extend(columns.to_columns());
+
+        self
+    }
 }
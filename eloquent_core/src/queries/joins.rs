@@ -1,4 +1,7 @@
-use crate::{Join, JoinType, QueryBuilder};
+use crate::{
+    Comparison, Condition, Join, JoinClause, JoinType, Logic, Operator, QueryBuilder,
+    SubqueryBuilder,
+};
 
 impl QueryBuilder {
     fn add_join(
@@ -13,6 +16,9 @@ impl QueryBuilder {
             left_hand: left_hand.to_string(),
             join_type,
             right_hand: right_hand.to_string(),
+            conditions: Vec::new(),
+            closures: Vec::new(),
+            subquery: None,
         });
 
         self
@@ -105,4 +111,252 @@ impl QueryBuilder {
     pub fn full_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Full)
     }
+
+    /// Inner join two tables together on the conditions built by the closure.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_on("airports", |join| {
+    ///         join.on_column("flights.destination", Comparison::Equal, "airports.code")
+    ///             .on_value("airports.active", Comparison::Equal, 1)
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights JOIN airports ON flights.destination = airports.code AND airports.active = 1"
+    /// );
+    /// ```
+    pub fn join_on<F>(mut self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        let clause = closure(JoinClause::new());
+
+        self.joins.push(Join {
+            table: table.to_string(),
+            left_hand: String::new(),
+            join_type: JoinType::Inner,
+            right_hand: String::new(),
+            conditions: clause.conditions,
+            closures: clause.closures,
+            subquery: None,
+        });
+
+        self
+    }
+
+    /// Inner join a derived table, referring to it by the alias.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let delays = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("delay_in_min", "avg_delay")
+    ///     .group_by("origin");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flights.flight_number", "delays.avg_delay"])
+    ///     .join_sub(delays, "delays", "flights.origin", "delays.origin");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flights.flight_number, delays.avg_delay FROM flights JOIN (SELECT origin, AVG(delay_in_min) AS avg_delay FROM flights GROUP BY origin) AS delays ON flights.origin = delays.origin"
+    /// );
+    /// ```
+    pub fn join_sub(
+        self,
+        subquery: SubqueryBuilder,
+        alias: &str,
+        left_hand: &str,
+        right_hand: &str,
+    ) -> Self {
+        self.add_sub_join(subquery, alias, left_hand, right_hand, JoinType::Inner)
+    }
+
+    /// Left join a derived table, referring to it by the alias.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let bookings = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("flight_id")
+    ///     .select_count("id", "booking_count")
+    ///     .group_by("flight_id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flights.flight_number", "bookings.booking_count"])
+    ///     .left_join_sub(bookings, "bookings", "flights.id", "bookings.flight_id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flights.flight_number, bookings.booking_count FROM flights LEFT JOIN (SELECT flight_id, COUNT(id) AS booking_count FROM bookings GROUP BY flight_id) AS bookings ON flights.id = bookings.flight_id"
+    /// );
+    /// ```
+    pub fn left_join_sub(
+        self,
+        subquery: SubqueryBuilder,
+        alias: &str,
+        left_hand: &str,
+        right_hand: &str,
+    ) -> Self {
+        self.add_sub_join(subquery, alias, left_hand, right_hand, JoinType::Left)
+    }
+
+    fn add_sub_join(
+        mut self,
+        subquery: SubqueryBuilder,
+        alias: &str,
+        left_hand: &str,
+        right_hand: &str,
+        join_type: JoinType,
+    ) -> Self {
+        self.joins.push(Join {
+            table: alias.to_string(),
+            left_hand: left_hand.to_string(),
+            join_type,
+            right_hand: right_hand.to_string(),
+            conditions: Vec::new(),
+            closures: Vec::new(),
+            subquery: Some(subquery),
+        });
+
+        self
+    }
+
+    /// Cross join two tables together, combining every row of both tables.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flights.flight_number", "seat_classes.name"])
+    ///     .cross_join("seat_classes");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flights.flight_number, seat_classes.name FROM flights CROSS JOIN seat_classes"
+    /// );
+    /// ```
+    pub fn cross_join(self, table: &str) -> Self {
+        self.add_join(table, "", "", JoinType::Cross)
+    }
+
+    /// Join two tables together using a join type chosen at runtime.
+    ///
+    /// ```
+    /// use eloquent_core::{JoinType, QueryBuilder};
+    ///
+    /// let include_unknown_airports = true;
+    ///
+    /// let join_type = if include_unknown_airports {
+    ///     JoinType::Left
+    /// } else {
+    ///     JoinType::Inner
+    /// };
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_type(
+    ///         join_type,
+    ///         "airports",
+    ///         "flights.origin_airport",
+    ///         "airports.code",
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights LEFT JOIN airports ON flights.origin_airport = airports.code"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{JoinType, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_type(JoinType::Cross, "airports", "", "");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights CROSS JOIN airports"
+    /// );
+    /// ```
+    pub fn join_type(
+        self,
+        join_type: JoinType,
+        table: &str,
+        left_hand: &str,
+        right_hand: &str,
+    ) -> Self {
+        self.add_join(table, left_hand, right_hand, join_type)
+    }
+
+    /// Keep only the rows without a matching row in another table, using `NOT EXISTS`.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .anti_join("bookings", "flights.id", "bookings.flight_id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE NOT EXISTS (SELECT 1 FROM bookings WHERE bookings.flight_id = flights.id)"
+    /// );
+    /// ```
+    pub fn anti_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+        self.add_exists_join(table, left_hand, right_hand, Operator::NotExists)
+    }
+
+    /// Keep only the rows with at least one matching row in another table, using `EXISTS`.
+    ///
+    /// Unlike a join, rows are not duplicated when there are multiple matches.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .semi_join("bookings", "flights.id", "bookings.flight_id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE EXISTS (SELECT 1 FROM bookings WHERE bookings.flight_id = flights.id)"
+    /// );
+    /// ```
+    pub fn semi_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+        self.add_exists_join(table, left_hand, right_hand, Operator::Exists)
+    }
+
+    fn add_exists_join(
+        mut self,
+        table: &str,
+        left_hand: &str,
+        right_hand: &str,
+        operator: Operator,
+    ) -> Self {
+        let subquery = SubqueryBuilder::new()
+            .table(table)
+            .select_raw("1", Vec::<i32>::new())
+            .where_column(right_hand, Comparison::Equal, left_hand);
+
+        self.conditions.push(Condition::new(
+            "",
+            operator,
+            Logic::And,
+            vec![Box::new(subquery)],
+        ));
+
+        self
+    }
 }
@@ -20,4 +20,48 @@ impl QueryBuilder {
 
         self
     }
+
+    /// Add a limit clause to the query unless a limit or pagination is already set.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .limit(10)
+    ///     .default_limit(100);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights LIMIT 10"
+    /// );
+    /// ```
+    pub fn default_limit(mut self, limit: u64) -> Self {
+        if self.limit.is_none() && self.paginate.is_none() {
+            self.limit = Some(limit);
+        }
+
+        self
+    }
+
+    /// Limit the query to a page of rows, pages are numbered from 1.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .for_page(3, 25);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights LIMIT 25 OFFSET 50"
+    /// );
+    /// ```
+    pub fn for_page(mut self, page: u64, per_page: u64) -> Self {
+        self.limit = Some(per_page);
+        self.offset = Some((page.max(1) - 1).saturating_mul(per_page));
+
+        self
+    }
 }
@@ -0,0 +1,139 @@
+use crate::{Columnable, Lock, LockMode, LockWait, QueryBuilder};
+
+impl QueryBuilder {
+    /// Lock the selected rows of specific tables for update.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flights.id", "airports.name"])
+    ///     .join("airports", "flights.origin_airport", "airports.code")
+    ///     .r#where("flights.id", 1)
+    ///     .for_update_of("flights");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flights.id, airports.name FROM flights JOIN airports ON flights.origin_airport = airports.code WHERE flights.id = 1 FOR UPDATE OF flights"
+    /// );
+    /// ```
+    pub fn for_update_of<T>(mut self, tables: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.lock = Some(Lock {
+            mode: LockMode::Update,
+            tables: tables.to_columns(),
+        });
+
+        self
+    }
+
+    /// Lock the selected rows for update.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("seats")
+    ///     .r#where("flight_id", 1)
+    ///     .limit(1)
+    ///     .for_update();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM seats WHERE flight_id = 1 LIMIT 1 FOR UPDATE"
+    /// );
+    /// ```
+    pub fn for_update(mut self) -> Self {
+        self.lock = Some(Lock {
+            mode: LockMode::Update,
+            tables: Vec::new(),
+        });
+
+        self
+    }
+
+    /// Lock the selected rows against updates while allowing other shared locks.
+    ///
+    /// MySQL renders the legacy `LOCK IN SHARE MODE` unless a modifier requires `FOR SHARE`.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("seats")
+    ///     .r#where("flight_id", 1)
+    ///     .for_share();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM seats WHERE flight_id = 1 LOCK IN SHARE MODE"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("seats")
+    ///     .dialect(Dialect::Postgres)
+    ///     .r#where("flight_id", 1)
+    ///     .for_share();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM seats WHERE flight_id = 1 FOR SHARE"
+    /// );
+    /// ```
+    pub fn for_share(mut self) -> Self {
+        self.lock = Some(Lock {
+            mode: LockMode::Share,
+            tables: Vec::new(),
+        });
+
+        self
+    }
+
+    /// Skip rows that are locked by another transaction instead of waiting.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("jobs")
+    ///     .r#where("status", "pending")
+    ///     .limit(10)
+    ///     .for_update()
+    ///     .skip_locked();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM jobs WHERE status = 'pending' LIMIT 10 FOR UPDATE SKIP LOCKED"
+    /// );
+    /// ```
+    pub fn skip_locked(mut self) -> Self {
+        self.lock_wait = Some(LockWait::SkipLocked);
+
+        self
+    }
+
+    /// Fail immediately when a selected row is locked by another transaction.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("seats")
+    ///     .r#where("flight_id", 1)
+    ///     .for_update()
+    ///     .no_wait();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM seats WHERE flight_id = 1 FOR UPDATE NOWAIT"
+    /// );
+    /// ```
+    pub fn no_wait(mut self) -> Self {
+        self.lock_wait = Some(LockWait::NoWait);
+
+        self
+    }
+}
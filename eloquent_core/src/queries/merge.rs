@@ -0,0 +1,69 @@
+use crate::{Merge, QueryBuilder};
+
+impl QueryBuilder {
+    /// Merge rows into the target table, supported by Postgres and SQL Server.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .merge_into("flights")
+    ///     .using("staged_flights AS s")
+    ///     .on("flights.id", "s.id")
+    ///     .when_matched_update(vec![("status", "s.status")])
+    ///     .when_not_matched_insert(vec!["id", "status"], vec!["s.id", "s.status"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "MERGE INTO flights USING staged_flights AS s ON flights.id = s.id WHEN MATCHED THEN UPDATE SET status = s.status WHEN NOT MATCHED THEN INSERT (id, status) VALUES (s.id, s.status)"
+    /// );
+    /// ```
+    pub fn merge_into(mut self, target: &str) -> Self {
+        self.table = Some(target.to_string());
+        self.merge.get_or_insert_with(Merge::default);
+
+        self
+    }
+
+    /// Set the source table of a merge.
+    pub fn using(mut self, source: &str) -> Self {
+        self.merge.get_or_insert_with(Merge::default).source = Some(source.to_string());
+
+        self
+    }
+
+    /// Set the condition matching source rows to target rows in a merge.
+    pub fn on(mut self, left_hand: &str, right_hand: &str) -> Self {
+        self.merge.get_or_insert_with(Merge::default).on =
+            Some((left_hand.to_string(), right_hand.to_string()));
+
+        self
+    }
+
+    /// Update the matched target rows with source expressions in a merge.
+    pub fn when_matched_update(mut self, columns: Vec<(&str, &str)>) -> Self {
+        self.merge
+            .get_or_insert_with(Merge::default)
+            .matched_updates
+            .extend(
+                columns
+                    .into_iter()
+                    .map(|(column, value)| (column.to_string(), value.to_string())),
+            );
+
+        self
+    }
+
+    /// Insert the source rows without a match into the target table in a merge.
+    pub fn when_not_matched_insert(mut self, columns: Vec<&str>, values: Vec<&str>) -> Self {
+        self.merge
+            .get_or_insert_with(Merge::default)
+            .not_matched_insert = Some((
+            columns.iter().map(|column| column.to_string()).collect(),
+            values.iter().map(|value| value.to_string()).collect(),
+        ));
+
+        self
+    }
+}
@@ -1,12 +1,22 @@
 pub mod conditions;
+pub mod ctes;
+pub mod cursor;
 pub mod deletes;
+pub mod explain;
+pub mod from_function;
+pub mod from_subquery;
+pub mod from_values;
 pub mod group_by;
 pub mod havings;
 pub mod inserts;
 pub mod joins;
 pub mod limit;
+pub mod lock;
+pub mod merge;
 pub mod offset;
 pub mod order_by;
 pub mod paginate;
 pub mod selects;
+pub mod shards;
+pub mod unions;
 pub mod updates;
@@ -1,4 +1,4 @@
-use crate::{Order, OrderColumn, QueryBuilder};
+use crate::{Nulls, Order, OrderColumn, OrderKind, QueryBuilder, ToSql};
 
 impl QueryBuilder {
     /// Add an order by clause to the query in ascending order.
@@ -33,6 +33,9 @@ impl QueryBuilder {
         self.order_by.push(OrderColumn {
             column: column.to_string(),
             order: Order::Asc,
+            nulls: None,
+            kind: OrderKind::Column,
+            values: Vec::new(),
         });
 
         self
@@ -70,6 +73,122 @@ impl QueryBuilder {
         self.order_by.push(OrderColumn {
             column: column.to_string(),
             order: Order::Desc,
+            nulls: None,
+            kind: OrderKind::Column,
+            values: Vec::new(),
+        });
+
+        self
+    }
+
+    /// Add an order by clause to the query with null values placed first or last.
+    ///
+    /// MySQL and SQL Server have no `NULLS FIRST` or `NULLS LAST`, so a leading sort key is added instead.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, Nulls, Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .order_by_nulls("arrived_at", Order::Desc, Nulls::Last);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights ORDER BY arrived_at DESC NULLS LAST"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Nulls, Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .order_by_nulls("arrived_at", Order::Asc, Nulls::First);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights ORDER BY CASE WHEN arrived_at IS NULL THEN 0 ELSE 1 END, arrived_at ASC"
+    /// );
+    /// ```
+    pub fn order_by_nulls(mut self, column: &str, order: Order, nulls: Nulls) -> Self {
+        self.order_by.push(OrderColumn {
+            column: column.to_string(),
+            order,
+            nulls: Some(nulls),
+            kind: OrderKind::Column,
+            values: Vec::new(),
+        });
+
+        self
+    }
+
+    /// Add a raw expression to the order by clause, with `?` placeholders for the values.
+    ///
+    /// The expression is not validated against the selected columns.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("tickets")
+    ///     .select(vec!["id", "status"])
+    ///     .order_by_raw("FIELD(status, ?, ?, ?)", vec!["new", "open", "closed"])
+    ///     .order_by_desc("id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT id, status FROM tickets ORDER BY FIELD(status, 'new', 'open', 'closed'), id DESC"
+    /// );
+    /// ```
+    pub fn order_by_raw(mut self, expression: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        self.order_by.push(OrderColumn {
+            column: expression.to_string(),
+            order: Order::Asc,
+            nulls: None,
+            kind: OrderKind::Raw,
+            values: values
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn ToSql>)
+                .collect(),
+        });
+
+        self
+    }
+
+    /// Order the rows randomly, e.g. to select a random sample together with a limit.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("passengers")
+    ///     .order_by_random()
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM passengers ORDER BY RAND() LIMIT 10"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("passengers")
+    ///     .dialect(Dialect::Postgres)
+    ///     .order_by_random()
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM passengers ORDER BY RANDOM() LIMIT 10"
+    /// );
+    /// ```
+    pub fn order_by_random(mut self) -> Self {
+        self.order_by.push(OrderColumn {
+            column: String::new(),
+            order: Order::Asc,
+            nulls: None,
+            kind: OrderKind::Random,
+            values: Vec::new(),
         });
 
         self
@@ -1,4 +1,7 @@
-use crate::{Columnable, Function, QueryBuilder, Select, Selectable, ToSql};
+use crate::{
+    CaseBuilder, Columnable, Function, Literal, Order, QueryBuilder, Select, Selectable,
+    SubqueryBuilder, ToSql, Window,
+};
 
 impl QueryBuilder {
     /// Select single or multiple columns from the table.
@@ -56,12 +59,39 @@ impl QueryBuilder {
                 function: None,
                 column: column.to_string(),
                 alias: None,
+                values: Vec::new(),
+                window: None,
             });
         }
 
         self
     }
 
+    /// Replace any previously selected columns with the given columns.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["id", "origin"])
+    ///     .select_as("destination", "to")
+    ///     .select_only(vec!["origin", "destination"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, destination FROM flights"
+    /// );
+    /// ```
+    pub fn select_only<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.selects.clear();
+
+        self.select(columns)
+    }
+
     /// Select a single column from the table with an alias.
     ///
     /// ```
@@ -85,6 +115,8 @@ impl QueryBuilder {
             function: None,
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -121,15 +153,87 @@ impl QueryBuilder {
     /// );
     /// ```
     pub fn select_raw(mut self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
-        let mut formatted_raw = raw.to_string();
-        for value in values {
-            formatted_raw = formatted_raw.replacen('?', &value.to_sql().unwrap(), 1);
+        self.selects.push(Select {
+            function: None,
+            column: raw.to_string(),
+            alias: None,
+            values: values
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn ToSql>)
+                .collect(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select a `NULL` placeholder column with an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("passengers")
+    ///     .select("id")
+    ///     .select_null("middle_name");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT id, NULL AS middle_name FROM passengers"
+    /// );
+    /// ```
+    pub fn select_null(mut self, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: None,
+            column: "NULL".to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select a `CASE` expression with an alias, built from `WHEN ... THEN ...` branches and an optional `ELSE`.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .select_case(
+    ///         |case| {
+    ///             case.when("status", "delayed", 1)
+    ///                 .when("status", "cancelled", 2)
+    ///                 .else_value(0)
+    ///         },
+    ///         "disruption",
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number, CASE WHEN status = 'delayed' THEN 1 WHEN status = 'cancelled' THEN 2 ELSE 0 END AS disruption FROM flights"
+    /// );
+    /// ```
+    pub fn select_case<F>(mut self, closure: F, alias: &str) -> Self
+    where
+        F: FnOnce(CaseBuilder) -> CaseBuilder,
+    {
+        let case = closure(CaseBuilder::new());
+
+        if case.whens.is_empty() {
+            self.empty_cases.push(alias.to_string());
         }
 
+        let (expression, values) = case.into_expression();
+
         self.selects.push(Select {
             function: None,
-            column: formatted_raw.to_string(),
-            alias: None,
+            column: expression,
+            alias: Some(alias.to_string()),
+            values,
+            window: None,
         });
 
         self
@@ -157,6 +261,82 @@ impl QueryBuilder {
             function: Some(Function::Count),
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the count of distinct values in a column from the table.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_count_distinct("destination", "destination_count")
+    ///     .group_by("origin")
+    ///     .having_gt("destination_count", 3);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, COUNT(DISTINCT destination) AS destination_count FROM flights GROUP BY origin HAVING destination_count > 3"
+    /// );
+    /// ```
+    pub fn select_count_distinct<T>(mut self, column: T, alias: &str) -> Self
+    where
+        T: Selectable,
+    {
+        self.selects.push(Select {
+            function: Some(Function::CountDistinct),
+            column: column.to_select_column(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the count of all rows from the table without an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_count_all();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT COUNT(*) FROM flights"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_count_all()
+    ///     .group_by("origin")
+    ///     .having_gt("COUNT(*)", 5);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, COUNT(*) FROM flights GROUP BY origin HAVING COUNT(*) > 5"
+    /// );
+    /// ```
+    pub fn select_count_all(mut self) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::Count),
+            column: "*".to_string(),
+            alias: None,
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -184,6 +364,8 @@ impl QueryBuilder {
             function: Some(Function::Min),
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -211,6 +393,8 @@ impl QueryBuilder {
             function: Some(Function::Max),
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -230,6 +414,22 @@ impl QueryBuilder {
     ///     "SELECT AVG(flight_duration) AS flight_duration_avg FROM flights"
     /// );
     /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("airports.city")
+    ///     .select_avg("flights.duration", "avg")
+    ///     .join("airports", "flights.origin_airport", "airports.code")
+    ///     .group_by("airports.city");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT airports.city, AVG(flights.duration) AS avg FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY airports.city"
+    /// );
+    /// ```
     pub fn select_avg<T>(mut self, column: T, alias: &str) -> Self
     where
         T: Selectable,
@@ -238,6 +438,37 @@ impl QueryBuilder {
             function: Some(Function::Avg),
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the average of distinct values in a column from the table.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_avg_distinct("flight_duration", "flight_duration_avg");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT AVG(DISTINCT flight_duration) AS flight_duration_avg FROM flights"
+    /// );
+    /// ```
+    pub fn select_avg_distinct<T>(mut self, column: T, alias: &str) -> Self
+    where
+        T: Selectable,
+    {
+        self.selects.push(Select {
+            function: Some(Function::AvgDistinct),
+            column: column.to_select_column(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -265,6 +496,198 @@ impl QueryBuilder {
             function: Some(Function::Sum),
             column: column.to_select_column(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the sum of distinct values in a column from the table.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_sum_distinct("flight_duration", "flight_duration_sum");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT SUM(DISTINCT flight_duration) AS flight_duration_sum FROM flights"
+    /// );
+    /// ```
+    pub fn select_sum_distinct<T>(mut self, column: T, alias: &str) -> Self
+    where
+        T: Selectable,
+    {
+        self.selects.push(Select {
+            function: Some(Function::SumDistinct),
+            column: column.to_select_column(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the values of a column in each group joined into a single string.
+    ///
+    /// MySQL and SQLite use `GROUP_CONCAT`, Postgres and SQL Server use `STRING_AGG` and the ANSI dialect
+    /// uses `LISTAGG`. The separator is bound like any other value, except in MySQL which only accepts a literal.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let query = || {
+    ///     QueryBuilder::new()
+    ///         .table("flights")
+    ///         .select("origin")
+    ///         .select_group_concat("destination", ", ", "destinations")
+    ///         .group_by("origin")
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query().sql().unwrap(),
+    ///     "SELECT origin, GROUP_CONCAT(destination SEPARATOR ', ') AS destinations FROM flights GROUP BY origin"
+    /// );
+    /// assert_eq!(
+    ///     query().dialect(Dialect::Postgres).sql().unwrap(),
+    ///     "SELECT origin, STRING_AGG(destination, ', ') AS destinations FROM flights GROUP BY origin"
+    /// );
+    /// ```
+    pub fn select_group_concat<T>(mut self, column: T, separator: &str, alias: &str) -> Self
+    where
+        T: Selectable,
+    {
+        self.selects.push(Select {
+            function: Some(Function::GroupConcat {
+                separator: Literal(separator.to_string()),
+                distinct: false,
+            }),
+            column: column.to_select_column(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the distinct values of a column in each group joined into a single string.
+    ///
+    /// Not supported by SQLite and SQL Server.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let query = || {
+    ///     QueryBuilder::new()
+    ///         .table("flights")
+    ///         .select("origin")
+    ///         .select_group_concat_distinct("destination", ", ", "destinations")
+    ///         .group_by("origin")
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query().sql().unwrap(),
+    ///     "SELECT origin, GROUP_CONCAT(DISTINCT destination SEPARATOR ', ') AS destinations FROM flights GROUP BY origin"
+    /// );
+    /// assert_eq!(
+    ///     query().dialect(Dialect::Postgres).sql().unwrap(),
+    ///     "SELECT origin, STRING_AGG(DISTINCT destination, ', ') AS destinations FROM flights GROUP BY origin"
+    /// );
+    /// ```
+    pub fn select_group_concat_distinct<T>(
+        mut self,
+        column: T,
+        separator: &str,
+        alias: &str,
+    ) -> Self
+    where
+        T: Selectable,
+    {
+        self.selects.push(Select {
+            function: Some(Function::GroupConcat {
+                separator: Literal(separator.to_string()),
+                distinct: true,
+            }),
+            column: column.to_select_column(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select whether a column is aggregated in a rolled up row, `1` for subtotal rows and `0` otherwise.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_grouping("origin", "is_total")
+    ///     .select_sum("passengers", "total_passengers")
+    ///     .group_by("origin");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, GROUPING(origin) AS is_total, SUM(passengers) AS total_passengers FROM flights GROUP BY origin"
+    /// );
+    /// ```
+    pub fn select_grouping(mut self, column: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::Grouping),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the concatenation of columns, rendered with `||` where the dialect supports it.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("passengers")
+    ///     .select_concat(vec!["first_name", "last_name"], "full_name");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT CONCAT(first_name, last_name) AS full_name FROM passengers"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("passengers")
+    ///     .select_concat(vec!["first_name", "last_name"], "full_name");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT first_name || last_name AS full_name FROM passengers"
+    /// );
+    /// ```
+    pub fn select_concat(mut self, columns: Vec<&str>, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::Concat(
+                columns.iter().map(|column| column.to_string()).collect(),
+            )),
+            column: columns.join(", "),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -292,6 +715,230 @@ impl QueryBuilder {
             function: Some(Function::Distinct),
             column: column.to_select_column(),
             alias: None,
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select a column cast to another type.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_cast("departure_time", "DATE", "departure_date");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT CAST(departure_time AS DATE) AS departure_date FROM flights"
+    /// );
+    /// ```
+    pub fn select_cast(mut self, column: &str, data_type: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: None,
+            column: format!("CAST({} AS {})", column, data_type),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select multiple columns cast to other types.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_cast_all(vec![
+    ///         ("id", "VARCHAR(36)", "flight_id"),
+    ///         ("departure_time", "DATE", "departure_date"),
+    ///         ("ticket_price", "DECIMAL(10, 2)", "price"),
+    ///     ]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT CAST(id AS VARCHAR(36)) AS flight_id, CAST(departure_time AS DATE) AS departure_date, CAST(ticket_price AS DECIMAL(10, 2)) AS price FROM flights"
+    /// );
+    /// ```
+    pub fn select_cast_all(self, columns: Vec<(&str, &str, &str)>) -> Self {
+        columns
+            .into_iter()
+            .fold(self, |builder, (column, data_type, alias)| {
+                builder.select_cast(column, data_type, alias)
+            })
+    }
+
+    /// Keep only the first row for every distinct combination of the given columns.
+    ///
+    /// Postgres renders `DISTINCT ON` and moves its columns to the front of the ordering,
+    /// other dialects keep the first row of every partition with `ROW_NUMBER()`.
+    /// The rewrite names every selected column in the outer query, so it needs explicit columns with
+    /// aliases for expressions, and cannot be combined with row locks or shards.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .select(vec!["origin", "departed_at"])
+    ///     .distinct_on_or_group("origin")
+    ///     .order_by_asc("origin")
+    ///     .order_by_desc("departed_at");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT DISTINCT ON (origin) origin, departed_at FROM flights ORDER BY origin ASC, departed_at DESC"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["origin", "departed_at"])
+    ///     .distinct_on_or_group("origin")
+    ///     .order_by_asc("origin")
+    ///     .order_by_desc("departed_at");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, departed_at FROM (SELECT origin, departed_at, ROW_NUMBER() OVER (PARTITION BY origin ORDER BY origin ASC, departed_at DESC) AS row_num FROM flights) AS flights WHERE row_num = 1 ORDER BY origin ASC, departed_at DESC"
+    /// );
+    /// ```
+    pub fn distinct_on_or_group<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.distinct_on.extend(columns.to_columns());
+
+        self
+    }
+
+    /// Select whether a subquery returns any rows.
+    ///
+    /// ```
+    /// use eloquent_core::{Comparison, QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("orders")
+    ///     .select_raw("1", Vec::<i32>::new())
+    ///     .where_column("orders.user_id", Comparison::Equal, "users.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("users")
+    ///     .select("id")
+    ///     .select_exists(subquery, "has_orders");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT id, EXISTS(SELECT 1 FROM orders WHERE orders.user_id = users.id) AS has_orders FROM users"
+    /// );
+    /// ```
+    pub fn select_exists(mut self, subquery: SubqueryBuilder, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: None,
+            column: format!("EXISTS{}", subquery.to_select_column()),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    /// Select the number of distinct values of a column within each partition, keeping every row.
+    ///
+    /// MySQL, Postgres, SQLite and SQL Server reject `DISTINCT` inside a window function,
+    /// so this is only supported by the [`Dialect::AnsiStandard`](crate::Dialect::AnsiStandard) dialect.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Dialect::AnsiStandard)
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_count_distinct_over("destination", vec!["origin"], "destination_count");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, COUNT(DISTINCT destination) OVER (PARTITION BY origin) AS \"destination_count\" FROM \"flights\""
+    /// );
+    /// ```
+    pub fn select_count_distinct_over(
+        mut self,
+        column: &str,
+        partition_by: Vec<&str>,
+        alias: &str,
+    ) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::CountDistinct),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: Some(Window {
+                partition_by: partition_by
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect(),
+                order_by: Vec::new(),
+            }),
+        });
+
+        self
+    }
+
+    /// Select a window function, such as a ranking or a running total, computed over the partitions of the rows.
+    ///
+    /// ```
+    /// use eloquent_core::{Order, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("employees")
+    ///     .select(vec!["name", "department", "salary"])
+    ///     .select_window(
+    ///         "ROW_NUMBER()",
+    ///         vec!["department"],
+    ///         vec![("salary", Order::Desc)],
+    ///         "rank_in_department",
+    ///     )
+    ///     .select_window("SUM(salary)", vec![], vec![("hired_at", Order::Asc)], "running_total");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT name, department, salary, ROW_NUMBER() OVER (PARTITION BY department ORDER BY salary DESC) AS rank_in_department, SUM(salary) OVER (ORDER BY hired_at ASC) AS running_total FROM employees"
+    /// );
+    /// ```
+    pub fn select_window(
+        mut self,
+        function: &str,
+        partition_by: Vec<&str>,
+        order_by: Vec<(&str, Order)>,
+        alias: &str,
+    ) -> Self {
+        self.selects.push(Select {
+            function: None,
+            column: function.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: Some(Window {
+                partition_by: partition_by
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect(),
+                order_by: order_by
+                    .into_iter()
+                    .map(|(column, order)| (column.to_string(), order))
+                    .collect(),
+            }),
         });
 
         self
@@ -0,0 +1,51 @@
+use crate::{QueryBuilder, Shards};
+
+impl QueryBuilder {
+    /// Run the query against multiple shards of the table combined with UNION ALL.
+    ///
+    /// Each shard targets the table name followed by the suffix returned for its index,
+    /// starting at zero. The ORDER BY, LIMIT and OFFSET clauses apply to the combined result.
+    /// Only a SELECT with at least one shard can be sharded.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["id", "origin"])
+    ///     .r#where("destination", "AMS")
+    ///     .sharded(2, |shard| format!("_{}", shard));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT id, origin FROM flights_0 WHERE destination = 'AMS' UNION ALL SELECT id, origin FROM flights_1 WHERE destination = 'AMS'"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["id", "origin"])
+    ///     .sharded(2, |shard| format!("_{}", shard))
+    ///     .order_by_desc("id")
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT id, origin FROM flights_0 UNION ALL SELECT id, origin FROM flights_1 ORDER BY id DESC LIMIT 10"
+    /// );
+    /// ```
+    pub fn sharded<F>(mut self, count: u32, suffix: F) -> Self
+    where
+        F: Fn(u32) -> String + 'static,
+    {
+        self.shards = Some(Shards {
+            count,
+            suffix: Box::new(suffix),
+        });
+
+        self
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{QueryBuilder, Union};
+
+impl QueryBuilder {
+    /// Combine the distinct rows of the query with the rows of another query.
+    ///
+    /// The ordering and limit of the outer query apply to the combined rows,
+    /// and the other query is rendered with the dialect and identifier quoting of this one.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .union(QueryBuilder::new().table("flights").select("destination"))
+    ///     .order_by_asc("origin")
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights UNION (SELECT destination FROM flights) ORDER BY origin ASC LIMIT 10"
+    /// );
+    /// ```
+    pub fn union(mut self, query: QueryBuilder) -> Self {
+        self.unions.push(Union { all: false, query });
+        self.inherit_format();
+
+        self
+    }
+
+    /// Combine all rows of the query with the rows of another query, keeping duplicates.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .r#where("status", "landed")
+    ///     .union_all(QueryBuilder::new().table("archived_flights").select("origin"))
+    ///     .union_all(QueryBuilder::new().table("charter_flights").select("origin"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights WHERE status = 'landed' UNION ALL (SELECT origin FROM archived_flights) UNION ALL (SELECT origin FROM charter_flights)"
+    /// );
+    /// ```
+    pub fn union_all(mut self, query: QueryBuilder) -> Self {
+        self.unions.push(Union { all: true, query });
+        self.inherit_format();
+
+        self
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{QueryBuilder, ToSql, Update};
+use crate::{Column, Condition, Logic, Operator, QueryBuilder, ToSql, Update, UpdateFrom};
 
 impl QueryBuilder {
     /// Update single or multiple columns in the table.
@@ -20,9 +20,151 @@ impl QueryBuilder {
     pub fn update(mut self, column: &str, value: impl ToSql + 'static) -> Self {
         self.updates.push(Update {
             column: column.to_string(),
+            operator: None,
             value: Box::new(value),
         });
 
         self
     }
+
+    /// Update columns from the matching rows of another table using the `UPDATE ... FROM` form of Postgres and SQLite.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .update_from(
+    ///         "schedules",
+    ///         vec![
+    ///             ("departure_time", "schedules.departure_time"),
+    ///             ("arrival_time", "schedules.arrival_time"),
+    ///         ],
+    ///         ("flights.schedule_id", "schedules.id"),
+    ///     )
+    ///     .r#where("schedules.status", "confirmed");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE flights SET departure_time = schedules.departure_time, arrival_time = schedules.arrival_time FROM schedules WHERE schedules.status = 'confirmed' AND flights.schedule_id = schedules.id"
+    /// );
+    /// ```
+    pub fn update_from(
+        mut self,
+        source_table: &str,
+        columns: Vec<(&str, &str)>,
+        join_condition: (&str, &str),
+    ) -> Self {
+        for (column, source_column) in columns {
+            self.updates.push(Update {
+                column: column.to_string(),
+                operator: None,
+                value: Box::new(Column(source_column.to_string())),
+            });
+        }
+
+        let (left_hand, right_hand) = join_condition;
+
+        self.update_from = Some(UpdateFrom {
+            table: source_table.to_string(),
+            condition: Condition::new(
+                left_hand,
+                Operator::Equal,
+                Logic::And,
+                vec![Box::new(Column(right_hand.to_string()))],
+            ),
+        });
+
+        self
+    }
+
+    /// Increment a column by one.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("articles")
+    ///     .increment("views")
+    ///     .update("updated_at", "2024-10-01 12:00:00")
+    ///     .r#where("id", 1);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE articles SET views = views + 1, updated_at = '2024-10-01 12:00:00' WHERE id = 1"
+    /// );
+    /// ```
+    pub fn increment(self, column: &str) -> Self {
+        self.increment_by(column, 1)
+    }
+
+    /// Increment a column by the given amount.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("accounts")
+    ///     .increment_by("balance", 25.5)
+    ///     .r#where("id", 1);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE accounts SET balance = balance + 25.5 WHERE id = 1"
+    /// );
+    /// ```
+    pub fn increment_by(mut self, column: &str, amount: impl ToSql + 'static) -> Self {
+        self.updates.push(Update {
+            column: column.to_string(),
+            operator: Some("+"),
+            value: Box::new(amount),
+        });
+
+        self
+    }
+
+    /// Decrement a column by one.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .decrement("available_seats")
+    ///     .r#where("id", 1);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE flights SET available_seats = available_seats - 1 WHERE id = 1"
+    /// );
+    /// ```
+    pub fn decrement(self, column: &str) -> Self {
+        self.decrement_by(column, 1)
+    }
+
+    /// Decrement a column by the given amount.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .decrement_by("available_seats", 3)
+    ///     .r#where("id", 1);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE flights SET available_seats = available_seats - 3 WHERE id = 1"
+    /// );
+    /// ```
+    pub fn decrement_by(mut self, column: &str, amount: impl ToSql + 'static) -> Self {
+        self.updates.push(Update {
+            column: column.to_string(),
+            operator: Some("-"),
+            value: Box::new(amount),
+        });
+
+        self
+    }
 }
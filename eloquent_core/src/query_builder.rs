@@ -1,4 +1,15 @@
-use crate::{compiler::build_statement, error::EloquentError, Action, QueryBuilder};
+use std::collections::HashMap;
+
+use crate::{
+    compiler::{
+        build_deallocate, build_execute, build_prepare, build_set_clause, build_statement,
+        build_statement_named, build_statement_with_bindings,
+    },
+    compilers::tags,
+    error::EloquentError,
+    Action, Dialect, FromSubquery, Function, IdentifierFormat, QueryBuilder, Variable,
+    ANNOTATION_END, ANNOTATION_START,
+};
 
 impl QueryBuilder {
     /// Create a new instance of the QueryBuilder.
@@ -7,40 +18,645 @@ impl QueryBuilder {
             table: None,
             selects: Vec::new(),
             inserts: Vec::new(),
+            insert_rows: Vec::new(),
+            on_duplicate_key_updates: Vec::new(),
+            conflict_target: None,
+            inserted_flag: None,
+            returning: Vec::new(),
+            upsert: None,
             updates: Vec::new(),
+            update_from: None,
             delete: false,
             conditions: Vec::new(),
             closures: Vec::new(),
             joins: Vec::new(),
             havings: Vec::new(),
             group_by: Vec::new(),
+            raw_group_by: Vec::new(),
             order_by: Vec::new(),
             limit: None,
             offset: None,
+            empty_cases: Vec::new(),
+            empty_insert: false,
             enable_checks: true,
+            strict_bindings: false,
+            multiple_statements: false,
+            dedupe_bindings: false,
+            annotate: false,
+            auto_group_by: false,
+            rollup: false,
             paginate: None,
+            cursor: None,
+            shards: None,
+            lock: None,
+            lock_wait: None,
+            explain: None,
+            merge: None,
+            from_values: None,
+            from_function: None,
+            from_subquery: None,
+            unions: Vec::new(),
+            ctes: Vec::new(),
+            tags: Vec::new(),
+            distinct_on: Vec::new(),
+            dialect: Dialect::default(),
+            use_as_keyword: true,
+            quote_identifiers: false,
         }
     }
 
     /// Set the table name for the query.
-    pub fn table(mut self, table: &str) -> Self {
+    pub fn table(mut self, table: impl ToString) -> Self {
         self.table = Some(table.to_string());
 
         self
     }
 
-    /// Skip the validation checks.
+    /// Set the SQL dialect used to render database specific syntax, defaults to MySQL.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, IntervalUnit, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .dialect(Dialect::Postgres)
+    ///     .where_within_last("departed_at", 7, IntervalUnit::Days);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departed_at >= NOW() - INTERVAL '7 days'"
+    /// );
+    /// ```
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self.inherit_format();
+
+        self
+    }
+
+    /// Render column and table aliases with or without the AS keyword, defaults to `true`.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights AS f")
+    ///     .select_as("f.origin", "departure_airport")
+    ///     .use_as_keyword(false);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT f.origin departure_airport FROM flights f"
+    /// );
+    /// ```
+    pub fn use_as_keyword(mut self, use_as_keyword: bool) -> Self {
+        self.use_as_keyword = use_as_keyword;
+        self.inherit_format();
+
+        self
+    }
+
+    /// Quote table names, aliases and insert or update columns with the quote character of the dialect.
+    ///
+    /// Without quoting, these identifiers may only contain letters, digits, underscores and dots:
+    /// tables, joins and merge sources with their aliases, CTE names without dots, select aliases,
+    /// returning columns, table function columns, and insert, update and conflict columns.
+    /// Collations, table function names, where fields and order by columns, which may also be an
+    /// aggregate over a single column, are never quoted and validated even with quoting.
+    ///
+    /// Expressions in where and order by clauses belong in their raw variants. Select and group by
+    /// columns accept expressions and are neither validated nor quoted, so names built at runtime
+    /// should be wrapped in [`Quoted`](crate::Quoted).
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("tmp-flights-2024 AS f")
+    ///     .select_as("f.origin", "from")
+    ///     .quote_identifiers();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT f.origin AS `from` FROM `tmp-flights-2024` AS `f`"
+    /// );
+    /// ```
+    pub fn quote_identifiers(mut self) -> Self {
+        self.quote_identifiers = true;
+        self.inherit_format();
+
+        self
+    }
+
+    /// Tag the query with a key and value, rendered together with the other tags as a trailing comment.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("orders")
+    ///     .tag("team", "payments")
+    ///     .tag("route", "/checkout");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM orders /* team=payments, route=/checkout */"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("orders")
+    ///     .tag("route", "*/ DROP TABLE orders; /*");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM orders /* route=* / DROP TABLE orders; / * */"
+    /// );
+    /// ```
+    pub fn tag(mut self, key: &str, value: &str) -> Self {
+        match self.tags.iter_mut().find(|(tag, _)| tag == key) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.tags.push((key.to_string(), value.to_string())),
+        }
+
+        self
+    }
+
+    /// Apply a reusable scope function to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// fn departed(query: QueryBuilder) -> QueryBuilder {
+    ///     query.where_not_null("departed_at")
+    /// }
+    ///
+    /// fn from_airport(query: QueryBuilder) -> QueryBuilder {
+    ///     query.r#where("origin", "AMS")
+    /// }
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .scope(departed)
+    ///     .scope(from_airport);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departed_at IS NOT NULL AND origin = 'AMS'"
+    /// );
+    /// ```
+    pub fn scope<F>(self, scope: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        scope(self)
+    }
+
+    /// Apply the closure to the query only when the condition is true.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let origin: Option<&str> = Some("AMS");
+    /// let destination: Option<&str> = None;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .when(origin.is_some(), |query| query.r#where("origin", origin.unwrap()))
+    ///     .when(destination.is_some(), |query| {
+    ///         query.r#where("destination", destination.unwrap())
+    ///     })
+    ///     .order_by_asc("departed_at");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin = 'AMS' ORDER BY departed_at ASC"
+    /// );
+    /// ```
+    pub fn when<F>(self, condition: bool, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        match condition {
+            true => closure(self),
+            false => self,
+        }
+    }
+
+    /// Apply the closure to the query only when the condition is false.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let include_cancelled = false;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .unless(include_cancelled, |query| query.where_not("status", "cancelled"))
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE status != 'cancelled' LIMIT 10"
+    /// );
+    /// ```
+    pub fn unless<F>(self, condition: bool, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        self.when(!condition, closure)
+    }
+
+    /// Wrap the query in a derived table and apply the closure to the outer query.
+    ///
+    /// Useful to filter on window function results, which cannot be referenced in the inner `WHERE`.
+    /// Tags, `EXPLAIN` and row locks move to the outer query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("*")
+    ///     .select_as(
+    ///         "ROW_NUMBER() OVER (PARTITION BY origin ORDER BY departed_at DESC)",
+    ///         "rn",
+    ///     )
+    ///     .wrap_and_filter("latest", |query| query.r#where("rn", 1));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM (SELECT *, ROW_NUMBER() OVER (PARTITION BY origin ORDER BY departed_at DESC) AS rn FROM flights) AS latest WHERE rn = 1"
+    /// );
+    /// ```
+    pub fn wrap_and_filter<F>(mut self, alias: &str, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let mut outer = QueryBuilder::new();
+
+        outer.dialect = self.dialect;
+        outer.use_as_keyword = self.use_as_keyword;
+        outer.quote_identifiers = self.quote_identifiers;
+        outer.enable_checks = self.enable_checks;
+        outer.strict_bindings = self.strict_bindings;
+        outer.multiple_statements = self.multiple_statements;
+        outer.dedupe_bindings = self.dedupe_bindings;
+        outer.annotate = self.annotate;
+
+        // statement level clauses only apply to the outermost query
+        outer.tags = std::mem::take(&mut self.tags);
+        outer.explain = self.explain.take();
+        outer.lock = self.lock.take();
+        outer.lock_wait = self.lock_wait.take();
+
+        outer.table = Some(alias.to_string());
+        outer.from_subquery = Some(FromSubquery {
+            query: Box::new(self),
+            alias: alias.to_string(),
+        });
+
+        closure(outer)
+    }
+
+    /// Copy the dialect and identifier settings onto the union members, which are compiled as part of this query.
+    pub(crate) fn inherit_format(&mut self) {
+        for union in self.unions.iter_mut() {
+            union.query.dialect = self.dialect;
+            union.query.use_as_keyword = self.use_as_keyword;
+            union.query.quote_identifiers = self.quote_identifiers;
+            union.query.inherit_format();
+        }
+    }
+
+    pub(crate) fn limits_rows(&self) -> bool {
+        !self.order_by.is_empty()
+            || self.limit.is_some()
+            || self.offset.is_some()
+            || self.paginate.is_some()
+    }
+
+    pub(crate) fn rewrites_distinct_on(&self) -> bool {
+        !self.distinct_on.is_empty() && self.dialect != Dialect::Postgres
+    }
+
+    pub(crate) fn identifier_format(&self) -> IdentifierFormat {
+        IdentifierFormat {
+            use_as_keyword: self.use_as_keyword,
+            quote: (self.quote_identifiers || self.dialect == Dialect::AnsiStandard)
+                .then_some(self.dialect),
+            dialect: self.dialect,
+        }
+    }
+
+    /// Mark the clause written to `sql` since `start` with a comment, when annotations are enabled.
+    pub(crate) fn annotate_clause(&self, label: &str, start: usize, sql: &mut String) {
+        if self.annotate && sql.len() > start {
+            sql.insert_str(
+                start,
+                &format!("{}{}{}", ANNOTATION_START, label, ANNOTATION_END),
+            );
+        }
+    }
+
+    /// Skip the validation checks, except for the identifier validation described in [`QueryBuilder::quote_identifiers`].
     pub fn skip_validation(mut self) -> Self {
         self.enable_checks = false;
 
         self
     }
 
+    /// Reject inlining values into the SQL string, so only the parameterized output methods succeed.
+    ///
+    /// ```
+    /// use eloquent_core::{error::EloquentError, QueryBuilder};
+    ///
+    /// let query = || {
+    ///     QueryBuilder::new()
+    ///         .table("flights")
+    ///         .r#where("origin", "AMS")
+    ///         .strict_bindings()
+    /// };
+    ///
+    /// assert!(matches!(query().sql(), Err(EloquentError::InliningDisabled)));
+    /// assert_eq!(
+    ///     query().sql_with_bindings().unwrap().0,
+    ///     "SELECT * FROM flights WHERE origin = ?"
+    /// );
+    /// ```
+    pub fn strict_bindings(mut self) -> Self {
+        self.strict_bindings = true;
+
+        self
+    }
+
+    /// Allow the query to compile to more than one statement, separated by semicolons.
+    ///
+    /// Used to emulate clauses a dialect lacks, such as `RETURNING` on MySQL.
+    pub fn multiple_statements(mut self) -> Self {
+        self.multiple_statements = true;
+
+        self
+    }
+
+    /// Reuse the placeholder of an equal value that is already bound, instead of binding it again.
+    ///
+    /// Only numbered placeholders can be reused, so this applies to the Postgres dialect.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .or_where("destination", "AMS")
+    ///     .dedupe_bindings(true)
+    ///     .sql_with_bindings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM flights WHERE origin = $1 OR destination = $1");
+    /// assert_eq!(bindings, vec![Variable::String("AMS".to_string())]);
+    /// ```
+    pub fn dedupe_bindings(mut self, dedupe_bindings: bool) -> Self {
+        self.dedupe_bindings = dedupe_bindings;
+
+        self
+    }
+
+    /// Precede the major clauses with a `-- label` comment on its own line, for reviewing generated SQL.
+    ///
+    /// The comments are only added to inlined SQL, never to the parameterized output.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let query = || {
+    ///     QueryBuilder::new()
+    ///         .table("flights")
+    ///         .select("origin")
+    ///         .r#where("status", "delayed")
+    ///         .order_by_asc("origin")
+    ///         .annotate(true)
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query().sql().unwrap(),
+    ///     "SELECT origin FROM flights\n-- filters\nWHERE status = 'delayed'\n-- ordering\nORDER BY origin ASC"
+    /// );
+    /// assert_eq!(
+    ///     query().sql_with_bindings().unwrap().0,
+    ///     "SELECT origin FROM flights WHERE status = ? ORDER BY origin ASC"
+    /// );
+    /// ```
+    pub fn annotate(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+
+        self
+    }
+
     /// Compile the query to a SQL string.
     pub fn sql(self) -> Result<String, EloquentError> {
         build_statement(&self)
     }
 
+    /// Compile a query counting the rows the query would return.
+    ///
+    /// Distinct, grouped and limited queries are wrapped in a subquery so the count matches their rows.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .r#where("status", "delayed")
+    ///     .order_by_asc("origin");
+    ///
+    /// assert_eq!(
+    ///     result.count().unwrap(),
+    ///     "SELECT COUNT(*) FROM flights WHERE status = 'delayed'"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_distinct("origin")
+    ///     .r#where("status", "delayed");
+    ///
+    /// assert_eq!(
+    ///     result.count().unwrap(),
+    ///     "SELECT COUNT(*) FROM (SELECT DISTINCT origin FROM flights WHERE status = 'delayed') AS sub"
+    /// );
+    /// ```
+    pub fn count(mut self) -> Result<String, EloquentError> {
+        let wraps = self
+            .selects
+            .iter()
+            .any(|select| select.function == Some(Function::Distinct))
+            || !self.distinct_on.is_empty()
+            || !self.group_by.is_empty()
+            || !self.havings.is_empty()
+            || !self.unions.is_empty()
+            || self.shards.is_some()
+            || self.limit.is_some()
+            || self.offset.is_some()
+            || self.paginate.is_some();
+
+        if !wraps {
+            self.selects.clear();
+            self.order_by.clear();
+
+            return build_statement(&self.select_count_all());
+        }
+
+        // tags belong after the outer query rather than inside the subquery
+        let query_tags = std::mem::take(&mut self.tags);
+
+        let mut sql = format!(
+            "SELECT COUNT(*) FROM {}",
+            self.identifier_format()
+                .alias(&format!("({})", build_statement(&self)?), "sub")
+        );
+
+        tags::format(&query_tags, &mut sql);
+
+        Ok(sql)
+    }
+
+    /// Compile the query to a SQL string with `?` placeholders and the values bound to them in order.
+    ///
+    /// The Postgres dialect numbers the placeholders as `$1`, `$2`, ... instead.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .limit(10)
+    ///     .offset(20)
+    ///     .sql_with_bindings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM flights WHERE origin = ? LIMIT ? OFFSET ?");
+    /// assert_eq!(
+    ///     bindings,
+    ///     vec![
+    ///         Variable::String("AMS".to_string()),
+    ///         Variable::UInt(10),
+    ///         Variable::UInt(20),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .where_gt("duration_in_min", 120)
+    ///     .sql_with_bindings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM flights WHERE origin = $1 AND duration_in_min > $2");
+    /// assert_eq!(
+    ///     bindings,
+    ///     vec![Variable::String("AMS".to_string()), Variable::Int(120)]
+    /// );
+    /// ```
+    pub fn sql_with_bindings(self) -> Result<(String, Vec<Variable>), EloquentError> {
+        build_statement_with_bindings(&self)
+    }
+
+    /// Compile the query to a SQL string with `:p0`, `:p1`, ... named placeholders and the values bound to each name.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .r#where("flight_duration", 120)
+    ///     .sql_named()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM flights WHERE origin = :p0 AND flight_duration = :p1");
+    /// assert_eq!(bindings["p0"], Variable::String("AMS".to_string()));
+    /// assert_eq!(bindings["p1"], Variable::Int(120));
+    /// ```
+    pub fn sql_named(self) -> Result<(String, HashMap<String, Variable>), EloquentError> {
+        build_statement_named(&self)
+    }
+
+    /// Compile only the `SET` clause of an update query, or `None` for other queries.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .update("status", "delayed")
+    ///     .update("delay_minutes", 45)
+    ///     .r#where("id", 1);
+    ///
+    /// assert_eq!(
+    ///     result.set_sql().unwrap(),
+    ///     Some("SET status = 'delayed', delay_minutes = 45".to_string())
+    /// );
+    /// ```
+    pub fn set_sql(&self) -> Result<Option<String>, EloquentError> {
+        build_set_clause(self)
+    }
+
+    /// Compile the query to a Postgres prepared statement, together with the values of its placeholders.
+    ///
+    /// ```
+    /// use eloquent_core::{Dialect, QueryBuilder, Variable};
+    ///
+    /// let (sql, bindings) = QueryBuilder::new()
+    ///     .dialect(Dialect::Postgres)
+    ///     .table("flights")
+    ///     .r#where("origin", "AMS")
+    ///     .where_gt("flight_duration", 120)
+    ///     .prepare("flights_from")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     sql,
+    ///     "PREPARE flights_from AS SELECT * FROM flights WHERE origin = $1 AND flight_duration > $2"
+    /// );
+    /// assert_eq!(
+    ///     QueryBuilder::execute_sql("flights_from", &bindings).unwrap(),
+    ///     "EXECUTE flights_from('AMS', 120)"
+    /// );
+    /// assert_eq!(
+    ///     QueryBuilder::deallocate_sql("flights_from").unwrap(),
+    ///     "DEALLOCATE flights_from"
+    /// );
+    /// ```
+    pub fn prepare(self, name: &str) -> Result<(String, Vec<Variable>), EloquentError> {
+        build_prepare(&self, name)
+    }
+
+    /// Compile the execution of a prepared statement with the given arguments.
+    pub fn execute_sql(name: &str, arguments: &[Variable]) -> Result<String, EloquentError> {
+        build_execute(name, arguments)
+    }
+
+    /// Compile the release of a prepared statement.
+    pub fn deallocate_sql(name: &str) -> Result<String, EloquentError> {
+        build_deallocate(name)
+    }
+
     /// Compile the query to a formatted SQL string.
     pub fn pretty_sql(self) -> Result<String, EloquentError> {
         let unformatted_sql = build_statement(&self)?;
@@ -65,6 +681,8 @@ impl QueryBuilder {
             Action::Update
         } else if self.delete {
             Action::Delete
+        } else if self.merge.is_some() {
+            Action::Merge
         } else {
             Action::Select
         }
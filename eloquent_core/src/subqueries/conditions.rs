@@ -1,4 +1,7 @@
-use crate::{Columnable, Condition, Logic, Operator, QueryBuilder, SubqueryBuilder, ToSql};
+use crate::{
+    Column, Columnable, Comparison, Condition, Logic, Operator, QueryBuilder, SubqueryBuilder,
+    ToSql,
+};
 
 impl SubqueryBuilder {
     fn add_condition(
@@ -8,6 +11,17 @@ impl SubqueryBuilder {
         logic: Logic,
         values: Vec<Box<dyn ToSql>>,
     ) -> Self {
+        // comparing with a missing optional value becomes a NULL check
+        let (operator, values) = match operator {
+            Operator::Equal if values.len() == 1 && values[0].is_null() => {
+                (Operator::IsNull, Vec::new())
+            }
+            Operator::NotEqual if values.len() == 1 && values[0].is_null() => {
+                (Operator::IsNotNull, Vec::new())
+            }
+            _ => (operator, values),
+        };
+
         self.conditions
             .push(Condition::new(field, operator, logic, values));
 
@@ -120,6 +134,34 @@ impl SubqueryBuilder {
         )
     }
 
+    pub fn where_not_between(
+        self,
+        field: &str,
+        min: impl ToSql + 'static,
+        max: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::NotBetween,
+            Logic::And,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    pub fn or_where_not_between(
+        self,
+        field: &str,
+        min: impl ToSql + 'static,
+        max: impl ToSql + 'static,
+    ) -> Self {
+        self.add_condition(
+            field,
+            Operator::NotBetween,
+            Logic::Or,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
     pub fn where_like(self, field: &str, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Like, Logic::And, vec![Box::new(value)])
     }
@@ -128,6 +170,14 @@ impl SubqueryBuilder {
         self.add_condition(field, Operator::Like, Logic::Or, vec![Box::new(value)])
     }
 
+    pub fn where_not_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::NotLike, Logic::And, vec![Box::new(value)])
+    }
+
+    pub fn or_where_not_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+        self.add_condition(field, Operator::NotLike, Logic::Or, vec![Box::new(value)])
+    }
+
     pub fn where_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
         let boxed_values = values
             .into_iter()
@@ -223,4 +273,40 @@ impl SubqueryBuilder {
     pub fn where_day(self, field: &str, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Day, Logic::And, vec![Box::new(value)])
     }
+
+    pub fn where_raw(self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(raw, Operator::Raw, Logic::And, values)
+    }
+
+    pub fn or_where_raw(self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn ToSql>)
+            .collect();
+
+        self.add_condition(raw, Operator::Raw, Logic::Or, values)
+    }
+
+    pub fn where_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_condition(
+            left_hand,
+            operator.into(),
+            Logic::And,
+            vec![Box::new(Column(right_hand.to_string()))],
+        )
+    }
+
+    pub fn or_where_column(self, left_hand: &str, operator: Comparison, right_hand: &str) -> Self {
+        self.add_condition(
+            left_hand,
+            operator.into(),
+            Logic::Or,
+            vec![Box::new(Column(right_hand.to_string()))],
+        )
+    }
 }
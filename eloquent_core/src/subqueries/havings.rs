@@ -1,4 +1,4 @@
-use crate::{Condition, Having, Logic, Operator, SubqueryBuilder};
+use crate::{Column, Comparison, Condition, Having, Logic, Operator, SubqueryBuilder};
 
 impl SubqueryBuilder {
     pub fn having(self, column: &str, value: i64) -> Self {
@@ -32,7 +32,29 @@ impl SubqueryBuilder {
                 operator: Operator::Between,
                 logic: Logic::And,
                 values: vec![Box::new(value_1), Box::new(value_2)],
+                collation: None,
             }],
+            compare_columns: false,
+        });
+
+        self
+    }
+
+    pub fn having_column(
+        mut self,
+        left_hand: &str,
+        operator: Comparison,
+        right_hand: &str,
+    ) -> Self {
+        self.havings.push(Having {
+            conditions: vec![Condition {
+                field: left_hand.to_string(),
+                operator: operator.into(),
+                logic: Logic::And,
+                values: vec![Box::new(Column(right_hand.to_string()))],
+                collation: None,
+            }],
+            compare_columns: true,
         });
 
         self
@@ -45,7 +67,9 @@ impl SubqueryBuilder {
                 operator,
                 logic: Logic::And,
                 values: vec![Box::new(value)],
+                collation: None,
             }],
+            compare_columns: false,
         });
 
         self
@@ -13,6 +13,9 @@ impl SubqueryBuilder {
             left_hand: left_hand.to_string(),
             join_type,
             right_hand: right_hand.to_string(),
+            conditions: Vec::new(),
+            closures: Vec::new(),
+            subquery: None,
         });
 
         self
@@ -33,4 +36,18 @@ impl SubqueryBuilder {
     pub fn full_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Full)
     }
+
+    pub fn cross_join(self, table: &str) -> Self {
+        self.add_join(table, "", "", JoinType::Cross)
+    }
+
+    pub fn join_type(
+        self,
+        join_type: JoinType,
+        table: &str,
+        left_hand: &str,
+        right_hand: &str,
+    ) -> Self {
+        self.add_join(table, left_hand, right_hand, join_type)
+    }
 }
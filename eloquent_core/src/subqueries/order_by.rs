@@ -1,10 +1,13 @@
-use crate::{Order, OrderColumn, SubqueryBuilder};
+use crate::{Order, OrderColumn, OrderKind, SubqueryBuilder, ToSql};
 
 impl SubqueryBuilder {
     pub fn order_by_asc(mut self, column: &str) -> Self {
         self.order_by.push(OrderColumn {
             column: column.to_string(),
             order: Order::Asc,
+            nulls: None,
+            kind: OrderKind::Column,
+            values: Vec::new(),
         });
 
         self
@@ -14,6 +17,24 @@ impl SubqueryBuilder {
         self.order_by.push(OrderColumn {
             column: column.to_string(),
             order: Order::Desc,
+            nulls: None,
+            kind: OrderKind::Column,
+            values: Vec::new(),
+        });
+
+        self
+    }
+
+    pub fn order_by_raw(mut self, expression: &str, values: Vec<impl ToSql + 'static>) -> Self {
+        self.order_by.push(OrderColumn {
+            column: expression.to_string(),
+            order: Order::Asc,
+            nulls: None,
+            kind: OrderKind::Raw,
+            values: values
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn ToSql>)
+                .collect(),
         });
 
         self
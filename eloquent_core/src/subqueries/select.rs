@@ -12,6 +12,8 @@ impl SubqueryBuilder {
                 function: None,
                 column: column.to_string(),
                 alias: None,
+                values: Vec::new(),
+                window: None,
             });
         }
 
@@ -23,21 +25,23 @@ impl SubqueryBuilder {
             function: None,
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
     }
 
     pub fn select_raw(mut self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
-        let mut formatted_raw = raw.to_string();
-        for value in values {
-            formatted_raw = formatted_raw.replacen('?', &value.to_sql().unwrap(), 1);
-        }
-
         self.selects.push(Select {
             function: None,
-            column: formatted_raw.to_string(),
+            column: raw.to_string(),
             alias: None,
+            values: values
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn ToSql>)
+                .collect(),
+            window: None,
         });
 
         self
@@ -48,6 +52,32 @@ impl SubqueryBuilder {
             function: Some(Function::Count),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_count_distinct(mut self, column: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::CountDistinct),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_count_all(mut self) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::Count),
+            column: "*".to_string(),
+            alias: None,
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -58,6 +88,8 @@ impl SubqueryBuilder {
             function: Some(Function::Min),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -68,6 +100,8 @@ impl SubqueryBuilder {
             function: Some(Function::Max),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -78,6 +112,20 @@ impl SubqueryBuilder {
             function: Some(Function::Avg),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_avg_distinct(mut self, column: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::AvgDistinct),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -88,6 +136,32 @@ impl SubqueryBuilder {
             function: Some(Function::Sum),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_sum_distinct(mut self, column: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::SumDistinct),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_grouping(mut self, column: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: Some(Function::Grouping),
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
         });
 
         self
@@ -98,8 +172,30 @@ impl SubqueryBuilder {
             function: Some(Function::Distinct),
             column: column.to_string(),
             alias: None,
+            values: Vec::new(),
+            window: None,
         });
 
         self
     }
+
+    pub fn select_cast(mut self, column: &str, data_type: &str, alias: &str) -> Self {
+        self.selects.push(Select {
+            function: None,
+            column: format!("CAST({} AS {})", column, data_type),
+            alias: Some(alias.to_string()),
+            values: Vec::new(),
+            window: None,
+        });
+
+        self
+    }
+
+    pub fn select_cast_all(self, columns: Vec<(&str, &str, &str)>) -> Self {
+        columns
+            .into_iter()
+            .fold(self, |builder, (column, data_type, alias)| {
+                builder.select_cast(column, data_type, alias)
+            })
+    }
 }
@@ -1,4 +1,4 @@
-use crate::SubqueryBuilder;
+use crate::{compiler::build_substatement, error::EloquentError, SubqueryBuilder};
 
 impl SubqueryBuilder {
     /// Create a new instance of the subquery builder.
@@ -22,6 +22,42 @@ impl SubqueryBuilder {
 
         self
     }
+
+    /// Compile the subquery to a SQL string without surrounding parentheses.
+    ///
+    /// ```
+    /// use eloquent_core::SubqueryBuilder;
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_max("duration_in_min", "max_duration_in_min");
+    ///
+    /// assert_eq!(
+    ///     subquery.sql().unwrap(),
+    ///     "SELECT MAX(duration_in_min) AS max_duration_in_min FROM flights"
+    /// );
+    /// ```
+    pub fn sql(&self) -> Result<String, EloquentError> {
+        build_substatement(self)
+    }
+
+    /// Compile the subquery to a parenthesized SQL string, ready to be embedded as a scalar value.
+    ///
+    /// ```
+    /// use eloquent_core::SubqueryBuilder;
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select_max("duration_in_min", "max_duration_in_min");
+    ///
+    /// assert_eq!(
+    ///     subquery.scalar_sql().unwrap(),
+    ///     "(SELECT MAX(duration_in_min) AS max_duration_in_min FROM flights)"
+    /// );
+    /// ```
+    pub fn scalar_sql(&self) -> Result<String, EloquentError> {
+        Ok(format!("({})", build_substatement(self)?))
+    }
 }
 
 impl Default for SubqueryBuilder {
@@ -0,0 +1,51 @@
+use crate::{error::EloquentError, QueryBuilder, TransactionBlock};
+
+impl TransactionBlock {
+    /// Create a new, empty transaction block.
+    pub fn new() -> Self {
+        Self {
+            queries: Vec::new(),
+        }
+    }
+
+    /// Add a query to the transaction block.
+    pub fn query(mut self, query: QueryBuilder) -> Self {
+        self.queries.push(query);
+
+        self
+    }
+
+    /// Compile every query, terminated by a semicolon, between `BEGIN;` and `COMMIT;`.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, TransactionBlock};
+    ///
+    /// let result = TransactionBlock::new()
+    ///     .query(QueryBuilder::new().table("flights").insert("flight_number", "KL123"))
+    ///     .query(QueryBuilder::new().table("flights").insert("flight_number", "KL456"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "BEGIN; INSERT INTO flights (flight_number) VALUES ('KL123'); INSERT INTO flights (flight_number) VALUES ('KL456'); COMMIT;"
+    /// );
+    /// ```
+    pub fn sql(self) -> Result<String, EloquentError> {
+        let mut sql = "BEGIN;".to_string();
+
+        for query in self.queries {
+            sql.push(' ');
+            sql.push_str(&query.sql()?);
+            sql.push(';');
+        }
+
+        sql.push_str(" COMMIT;");
+
+        Ok(sql)
+    }
+}
+
+impl Default for TransactionBlock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,19 +3,41 @@ use crate::{checks::*, error::EloquentError, PerformChecks, QueryBuilder};
 impl QueryBuilder {
     pub(crate) fn perform_checks(&self) -> Result<(), EloquentError> {
         missing_table::MissingTable::check(self)?;
+        multiple_from_sources::MultipleFromSources::check(self)?;
+        table_with_from_subquery::TableWithFromSubquery::check(self)?;
+        invalid_from_values::InvalidFromValues::check(self)?;
+        conflicting_upserts::ConflictingUpserts::check(self)?;
         multiple_crud_actions::MultipleCrudActions::check(self)?;
         duplicated_columns::DuplicatedColumns::check(self)?;
         duplicated_conditions::DuplicatedConditions::check(self)?;
+        duplicated_insert_columns::DuplicatedInsertColumns::check(self)?;
         group_by_without_selected_or_aggregate_function::GroupByWithoutSelectedOrAggregateFunction::check(self)?;
         having_clause_without_aggregate_function::HavingClauseWithoutAggregateFunction::check(
             self,
         )?;
         order_by_without_selected_or_aggregate_function::OrderByWithoutSelectedOrAggregateFunction::check(self)?;
+        rollup_without_group_by::RollupWithoutGroupBy::check(self)?;
+        ungroupable_expression::UngroupableExpression::check(self)?;
+        empty_case::EmptyCase::check(self)?;
+        empty_insert::EmptyInsert::check(self)?;
         cannot_apply_clause_on_insert::CannotApplyClauseOnInsert::check(self)?;
         cannot_apply_clause_on_update::CannotApplyClauseOnUpdate::check(self)?;
         cannot_apply_clause_on_delete::CannotApplyClauseOnDelete::check(self)?;
+        cannot_apply_clause_on_merge::CannotApplyClauseOnMerge::check(self)?;
         cannot_use_offset_limit_with_pagination::CannotUseOffsetLimitWithPagination::check(self)?;
+        join_without_conditions::JoinWithoutConditions::check(self)?;
+        invalid_lock::InvalidLock::check(self)?;
+        invalid_shards::InvalidShards::check(self)?;
+        lock_on_unknown_table::LockOnUnknownTable::check(self)?;
+        union_with_non_select::UnionWithNonSelect::check(self)?;
 
         Ok(())
     }
+
+    /// Validate the identifiers that are rendered without quoting or binding.
+    ///
+    /// Unlike the other checks this also runs with `skip_validation()`, since it guards against injected SQL.
+    pub(crate) fn validate_identifiers(&self) -> Result<(), EloquentError> {
+        invalid_identifier::InvalidIdentifier::check(self)
+    }
 }